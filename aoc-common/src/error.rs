@@ -0,0 +1,109 @@
+use std::fmt;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+#[derive(Debug)]
+pub enum InputError {
+  Io {
+    file: String,
+    source: std::io::Error
+  },
+  Parse {
+    file: Option<String>,
+    line: usize,
+    column: usize,
+    text: String,
+    reason: String
+  },
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl InputError {
+
+  pub fn io(file: &str, source: std::io::Error) -> Self {
+    InputError::Io { file: file.to_owned(), source }
+  }
+
+  /// Line and column are both 1-based, the column being counted in characters
+  pub fn parse(line: usize, column: usize, text: &str, reason: impl ToString) -> Self {
+    InputError::Parse { file: None, line, column, text: text.to_owned(), reason: reason.to_string() }
+  }
+
+  /// Error on a token of the line, reported at the column the token starts at. The token has to be a slice of
+  /// the line, as given by 'split', 'split_once' or 'trim', otherwise column 1 is reported
+  pub fn at_token(line: usize, text: &str, token: &str, reason: impl ToString) -> Self {
+    let offset = (token.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+    let column = text.get(..offset).map_or(1, |before| before.chars().count() + 1);
+    InputError::parse(line, column, text, reason)
+  }
+
+  /// Attaches the file the input was loaded from, so it shows up when reported
+  pub fn in_file(self, file: &str) -> Self {
+    match self {
+      InputError::Parse { line, column, text, reason, .. } =>
+        InputError::Parse { file: Some(file.to_owned()), line, column, text, reason },
+      other => other
+    }
+  }
+
+  /// Moves an error found within a block of the input down by the lines that come before the block
+  pub fn below(self, lines: usize) -> Self {
+    match self {
+      InputError::Parse { file, line, column, text, reason } =>
+        InputError::Parse { file, line: line + lines, column, text, reason },
+      other => other
+    }
+  }
+
+  /// Prints the error to stderr and terminates the process with a failure exit code
+  pub fn report(&self) -> ! {
+    eprintln!("{}", self);
+    std::process::exit(1)
+  }
+}
+
+impl fmt::Display for InputError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      InputError::Io { file, source } =>
+        write!(f, "🚨 Could not read '{}': {}", file, source),
+      InputError::Parse { file: Some(file), line, column, text, reason } =>
+        write!(f, "🚨 Invalid input '{}' at {}:{}:{}: {}", text, file, line, column, reason),
+      InputError::Parse { file: None, line, column, text, reason } =>
+        write!(f, "🚨 Invalid input '{}' at {}:{}: {}", text, line, column, reason),
+    }
+  }
+}
+
+impl std::error::Error for InputError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      InputError::Io { source, .. } => Some(source),
+      InputError::Parse { .. } => None,
+    }
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_error_location() {
+    let error = InputError::parse(3, 7, "x1", "invalid digit");
+    assert_eq!(error.to_string(), "🚨 Invalid input 'x1' at 3:7: invalid digit");
+    assert_eq!(error.in_file("input.txt").to_string(), "🚨 Invalid input 'x1' at input.txt:3:7: invalid digit");
+  }
+
+  #[test]
+  fn token_error_column() {
+    let line = "Game 1: 3 blüe, 4 pink";
+    let (_, pink) = line.split_once(", 4 ").unwrap();
+    assert_eq!(InputError::at_token(1, line, pink, "colour").to_string(),
+      format!("🚨 Invalid input '{}' at 1:19: colour", line));
+
+    let elsewhere = String::from("pink");
+    assert_eq!(InputError::at_token(1, line, &elsewhere, "colour").to_string(),
+      format!("🚨 Invalid input '{}' at 1:1: colour", line));
+  }
+}
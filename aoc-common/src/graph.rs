@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+pub type NodeIndex = usize;
+
+/// Directed graph with nodes interned to dense indexes, so algorithms can work on plain vectors, and every
+/// edge carrying a label such as a direction or a weight
+#[derive(Clone, Debug)]
+pub struct Graph<N, E> {
+  nodes: Vec<N>,
+  indexes: HashMap<N, NodeIndex>,
+  edges: Vec<Vec<(E, NodeIndex)>>,
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl<N: Clone + Eq + Hash, E> Graph<N, E> {
+  pub fn new() -> Graph<N, E> {
+    Graph { nodes: Vec::new(), indexes: HashMap::new(), edges: Vec::new() }
+  }
+
+  /// Index of the node, adding it when it is not known yet
+  pub fn add_node(&mut self, node: N) -> NodeIndex {
+    if let Some(&index) = self.indexes.get(&node) {
+      return index;
+    }
+
+    self.nodes.push(node.clone());
+    self.edges.push(Vec::new());
+    self.indexes.insert(node, self.nodes.len() - 1);
+    self.nodes.len() - 1
+  }
+
+  pub fn add_edge(&mut self, from: NodeIndex, label: E, to: NodeIndex) {
+    self.edges[from].push((label, to));
+  }
+
+  pub fn index_of(&self, node: &N) -> Option<NodeIndex> {
+    self.indexes.get(node).copied()
+  }
+
+  pub fn node(&self, index: NodeIndex) -> &N {
+    &self.nodes[index]
+  }
+
+  pub fn len(&self) -> usize {
+    self.nodes.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.nodes.is_empty()
+  }
+
+  pub fn nodes(&self) -> impl Iterator<Item = (NodeIndex, &N)> + '_ {
+    self.nodes.iter().enumerate()
+  }
+
+  pub fn edges(&self, from: NodeIndex) -> &[(E, NodeIndex)] {
+    &self.edges[from]
+  }
+
+  pub fn neighbours(&self, from: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+    self.edges[from].iter().map(|&(_, to)| to)
+  }
+
+  /// Destination of the first edge leaving 'from' with the given label
+  pub fn follow(&self, from: NodeIndex, label: &E) -> Option<NodeIndex>
+    where E: PartialEq {
+
+    self.edges[from].iter()
+      .find(|(edge_label, _)| edge_label == label)
+      .map(|&(_, to)| to)
+  }
+}
+
+impl<N: Clone + Eq + Hash, E: Clone> Graph<N, E> {
+  /// Adds the edge in both directions
+  pub fn add_undirected_edge(&mut self, first: NodeIndex, label: E, second: NodeIndex) {
+    self.add_edge(first, label.clone(), second);
+    self.add_edge(second, label, first);
+  }
+}
+
+impl<N: Clone + Eq + Hash, E> Default for Graph<N, E> {
+  fn default() -> Self {
+    Graph::new()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn nodes_are_interned() {
+    let mut graph: Graph<&str, ()> = Graph::new();
+    let first = graph.add_node("a");
+    let second = graph.add_node("b");
+    assert_eq!(graph.add_node("a"), first);
+    assert_eq!(graph.len(), 2);
+    assert_eq!(graph.index_of(&"b"), Some(second));
+    assert_eq!(graph.node(second), &"b");
+  }
+
+  #[test]
+  fn labelled_edges_are_followed() {
+    let mut graph: Graph<&str, char> = Graph::new();
+    let (a, b, c) = (graph.add_node("a"), graph.add_node("b"), graph.add_node("c"));
+    graph.add_edge(a, 'L', b);
+    graph.add_edge(a, 'R', c);
+    graph.add_undirected_edge(b, 'U', c);
+    assert_eq!(graph.follow(a, &'R'), Some(c));
+    assert_eq!(graph.follow(a, &'U'), None);
+    assert_eq!(graph.neighbours(c).collect::<Vec<_>>(), vec![b]);
+  }
+}
@@ -0,0 +1,506 @@
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Sub};
+use std::str::FromStr;
+
+use crate::error::InputError;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+pub type CoordinateUnit = i64;
+
+/// Position on a grid, with 'x' growing to the right and 'y' growing downwards
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug, PartialOrd, Ord)]
+pub struct Coordinate {
+  pub x: CoordinateUnit,
+  pub y: CoordinateUnit
+}
+
+/// Position in space, with 'z' growing upwards
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug, PartialOrd, Ord)]
+pub struct Coordinate3D {
+  pub x: CoordinateUnit,
+  pub y: CoordinateUnit,
+  pub z: CoordinateUnit
+}
+
+/// Heading of something moving across a grid, 'Up' being towards lower 'y' values
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, PartialOrd, Ord)]
+pub enum Direction {
+  Up,
+  Down,
+  Right,
+  Left
+}
+
+/// Dense rectangular grid stored in row-major order
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+  width: usize,
+  height: usize,
+  cells: Vec<T>
+}
+
+// ==================================================== CONSTANTS ====================================================
+const DELTAS_4: [Coordinate; 4] = [Coordinate::NORTH, Coordinate::EAST, Coordinate::SOUTH, Coordinate::WEST];
+const DELTAS_8: [Coordinate; 8] = [
+  Coordinate::new(-1, -1), Coordinate::NORTH, Coordinate::new(1, -1),
+  Coordinate::WEST, Coordinate::EAST,
+  Coordinate::new(-1, 1), Coordinate::SOUTH, Coordinate::new(1, 1),
+];
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Coordinate {
+  pub const NORTH: Coordinate = Coordinate::new(0, -1);
+  pub const SOUTH: Coordinate = Coordinate::new(0, 1);
+  pub const EAST: Coordinate = Coordinate::new(1, 0);
+  pub const WEST: Coordinate = Coordinate::new(-1, 0);
+
+  pub const fn new(x: CoordinateUnit, y: CoordinateUnit) -> Coordinate {
+    Coordinate { x, y }
+  }
+
+  pub fn manhattan_distance(&self, other: &Self) -> CoordinateUnit {
+    (self.x - other.x).abs() + (self.y - other.y).abs()
+  }
+}
+
+impl Add for Coordinate {
+  type Output = Coordinate;
+
+  fn add(self, other: Coordinate) -> Coordinate {
+    Coordinate::new(self.x + other.x, self.y + other.y)
+  }
+}
+
+impl Sub for Coordinate {
+  type Output = Coordinate;
+
+  fn sub(self, other: Coordinate) -> Coordinate {
+    Coordinate::new(self.x - other.x, self.y - other.y)
+  }
+}
+
+impl fmt::Display for Coordinate {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "({}, {})", self.x, self.y)
+  }
+}
+
+impl Coordinate3D {
+  pub const fn new(x: CoordinateUnit, y: CoordinateUnit, z: CoordinateUnit) -> Coordinate3D {
+    Coordinate3D { x, y, z }
+  }
+
+  /// Projection on the 'x'/'y' plane, dropping the height
+  pub fn xy(&self) -> Coordinate {
+    Coordinate::new(self.x, self.y)
+  }
+}
+
+impl Add for Coordinate3D {
+  type Output = Coordinate3D;
+
+  fn add(self, other: Coordinate3D) -> Coordinate3D {
+    Coordinate3D::new(self.x + other.x, self.y + other.y, self.z + other.z)
+  }
+}
+
+impl Sub for Coordinate3D {
+  type Output = Coordinate3D;
+
+  fn sub(self, other: Coordinate3D) -> Coordinate3D {
+    Coordinate3D::new(self.x - other.x, self.y - other.y, self.z - other.z)
+  }
+}
+
+impl fmt::Display for Coordinate3D {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "({}, {}, {})", self.x, self.y, self.z)
+  }
+}
+
+/// Parses 'x,y,z', spaces around the values being allowed
+impl FromStr for Coordinate3D {
+  type Err = String;
+
+  fn from_str(text: &str) -> Result<Coordinate3D, String> {
+    let values: Vec<CoordinateUnit> = text.split(',')
+      .map(|value| value.trim().parse().map_err(|_| format!("'{}' is not a number", value.trim())))
+      .collect::<Result<_, _>>()?;
+
+    match values[..] {
+      [x, y, z] => Ok(Coordinate3D::new(x, y, z)),
+      _ => Err(format!("expected 3 values but found {}", values.len())),
+    }
+  }
+}
+
+impl Direction {
+  /// Every direction, clockwise starting up
+  pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+  pub fn delta(self) -> Coordinate {
+    match self {
+      Direction::Up => Coordinate::NORTH,
+      Direction::Down => Coordinate::SOUTH,
+      Direction::Right => Coordinate::EAST,
+      Direction::Left => Coordinate::WEST,
+    }
+  }
+
+  pub fn turn_left(self) -> Direction {
+    match self {
+      Direction::Up => Direction::Left,
+      Direction::Left => Direction::Down,
+      Direction::Down => Direction::Right,
+      Direction::Right => Direction::Up,
+    }
+  }
+
+  pub fn turn_right(self) -> Direction {
+    self.turn_left().opposite()
+  }
+
+  pub fn opposite(self) -> Direction {
+    match self {
+      Direction::Up => Direction::Down,
+      Direction::Down => Direction::Up,
+      Direction::Right => Direction::Left,
+      Direction::Left => Direction::Right,
+    }
+  }
+}
+
+impl<T> Grid<T> {
+  pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+    assert_eq!(cells.len(), width * height, "🚨 Grid of {}x{} cannot hold {} cells", width, height, cells.len());
+    Grid { width, height, cells }
+  }
+
+  /// Builds a grid from a character map, converting every character with 'parse'
+  pub fn from_chars<F>(lines: &[Vec<char>], parse: F) -> Grid<T>
+  where
+    F: FnMut(char) -> T
+  {
+    let width = lines.first().map_or(0, Vec::len);
+    let height = lines.len();
+    if let Some(line_index) = lines.iter().position(|line| line.len() != width) {
+      panic!("🚨 Line '{}' does not have the expected width of '{}'", line_index + 1, width);
+    }
+
+    let cells: Vec<T> = lines.iter().flatten().copied().map(parse).collect();
+    Grid { width, height, cells }
+  }
+
+  /// Builds a grid from already parsed rows, which must all have the same width
+  pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+    let width = rows.first().map_or(0, Vec::len);
+    let height = rows.len();
+    if let Some(row_index) = rows.iter().position(|row| row.len() != width) {
+      panic!("🚨 Line '{}' does not have the expected width of '{}'", row_index + 1, width);
+    }
+
+    Grid { width, height, cells: rows.into_iter().flatten().collect() }
+  }
+
+  /// Builds a grid from the lines of a text, converting every character with 'parse'
+  pub fn from_text<F>(text: &str, parse: F) -> Grid<T>
+  where
+    F: FnMut(char) -> T
+  {
+    let lines: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+    Grid::from_chars(&lines, parse)
+  }
+
+  /// Builds a grid from the lines of a puzzle input, converting every character with 'parse'. Unlike
+  /// 'from_text' the input is checked: it must not be empty, its lines must all have the same width and
+  /// every character must be one 'parse' recognises, the error pointing at the first one that is not
+  pub fn parse_text<F>(text: &str, mut parse: F) -> Result<Grid<T>, InputError>
+  where
+    F: FnMut(char) -> Option<T>
+  {
+    let width = text.lines().next().map_or(0, |line| line.chars().count());
+    if width == 0 {
+      return Err(InputError::parse(1, 1, "", "map is empty"));
+    }
+
+    let mut cells: Vec<T> = Vec::with_capacity(text.len());
+    let mut height = 0;
+    for (line_index, line) in text.lines().enumerate() {
+      for (column_index, value) in line.chars().enumerate() {
+        if column_index == width {
+          return Err(InputError::parse(line_index + 1, width + 1, line, format!("line is wider than {}", width)));
+        }
+        let cell = parse(value)
+          .ok_or_else(|| InputError::parse(line_index + 1, column_index + 1, &value.to_string(), "not a map tile"))?;
+        cells.push(cell);
+      }
+      if cells.len() != (line_index + 1) * width {
+        let column = line.chars().count() + 1;
+        return Err(InputError::parse(line_index + 1, column, line, format!("line is narrower than {}", width)));
+      }
+      height += 1;
+    }
+
+    Ok(Grid { width, height, cells })
+  }
+
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  fn index_of(&self, coordinate: &Coordinate) -> Option<usize> {
+    match self.contains(coordinate) {
+      true => Some(coordinate.y as usize * self.width + coordinate.x as usize),
+      false => None
+    }
+  }
+
+  fn coordinate_of(&self, index: usize) -> Coordinate {
+    Coordinate::new((index % self.width) as CoordinateUnit, (index / self.width) as CoordinateUnit)
+  }
+
+  pub fn contains(&self, coordinate: &Coordinate) -> bool {
+    let inside_x = coordinate.x >= 0 && coordinate.x < self.width as CoordinateUnit;
+    let inside_y = coordinate.y >= 0 && coordinate.y < self.height as CoordinateUnit;
+    inside_x && inside_y
+  }
+
+  pub fn get(&self, coordinate: &Coordinate) -> Option<&T> {
+    self.index_of(coordinate).map(|index| &self.cells[index])
+  }
+
+  pub fn get_mut(&mut self, coordinate: &Coordinate) -> Option<&mut T> {
+    self.index_of(coordinate).map(|index| &mut self.cells[index])
+  }
+
+  /// Replaces the value at 'coordinate', returning the previous one or None when out of bounds
+  pub fn set(&mut self, coordinate: &Coordinate, value: T) -> Option<T> {
+    self.get_mut(coordinate).map(|cell| std::mem::replace(cell, value))
+  }
+
+  pub fn coordinates(&self) -> impl Iterator<Item = Coordinate> + '_ {
+    (0..self.cells.len()).map(|index| self.coordinate_of(index))
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = (Coordinate, &T)> + '_ {
+    self.cells.iter().enumerate()
+      .map(|(index, value)| (self.coordinate_of(index), value))
+  }
+
+  pub fn positions<P>(&self, predicate: P) -> impl Iterator<Item = Coordinate> + '_
+  where
+    P: Fn(&T) -> bool + 'static
+  {
+    self.iter()
+      .filter(move |(_, value)| predicate(value))
+      .map(|(coordinate, _)| coordinate)
+  }
+
+  /// In-bounds neighbours sharing an edge with 'coordinate', clockwise starting north
+  pub fn neighbours_4(&self, coordinate: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+    DELTAS_4.iter()
+      .map(move |&delta| coordinate + delta)
+      .filter(|neighbour| self.contains(neighbour))
+  }
+
+  /// In-bounds neighbours sharing an edge or a corner with 'coordinate', in reading order
+  pub fn neighbours_8(&self, coordinate: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+    DELTAS_8.iter()
+      .map(move |&delta| coordinate + delta)
+      .filter(|neighbour| self.contains(neighbour))
+  }
+
+  pub fn row(&self, y: usize) -> &[T] {
+    &self.cells[y * self.width..(y + 1) * self.width]
+  }
+
+  pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+    (0..self.height).map(|y| self.row(y))
+  }
+
+  pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+    self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+  }
+
+  pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+    (0..self.width).map(|x| self.column(x))
+  }
+
+  pub fn map<U, F>(&self, f: F) -> Grid<U>
+  where
+    F: FnMut(&T) -> U
+  {
+    Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+  }
+}
+
+impl<T: Clone> Grid<T> {
+  pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+    Grid { width, height, cells: vec![value; width * height] }
+  }
+
+  /// Swaps rows and columns, so the value at (x, y) ends up at (y, x)
+  pub fn transpose(&self) -> Grid<T> {
+    let cells = (0..self.width)
+      .flat_map(|x| self.column(x).cloned().collect::<Vec<T>>())
+      .collect();
+    Grid { width: self.height, height: self.width, cells }
+  }
+
+  /// Rotates a quarter turn clockwise, so the first column becomes the first row
+  pub fn rotate_clockwise(&self) -> Grid<T> {
+    let cells = (0..self.width)
+      .flat_map(|x| self.column(x).cloned().collect::<Vec<T>>().into_iter().rev())
+      .collect();
+    Grid { width: self.height, height: self.width, cells }
+  }
+
+  /// Rotates a quarter turn counter-clockwise, so the last column becomes the first row
+  pub fn rotate_counter_clockwise(&self) -> Grid<T> {
+    let cells = (0..self.width).rev()
+      .flat_map(|x| self.column(x).cloned().collect::<Vec<T>>())
+      .collect();
+    Grid { width: self.height, height: self.width, cells }
+  }
+}
+
+impl<T> Index<Coordinate> for Grid<T> {
+  type Output = T;
+
+  fn index(&self, coordinate: Coordinate) -> &T {
+    match self.get(&coordinate) {
+      Some(value) => value,
+      None => panic!("🚨 Coordinate '{}' is outside of the {}x{} grid", coordinate, self.width, self.height)
+    }
+  }
+}
+
+impl<T> IndexMut<Coordinate> for Grid<T> {
+  fn index_mut(&mut self, coordinate: Coordinate) -> &mut T {
+    let (width, height) = (self.width, self.height);
+    match self.get_mut(&coordinate) {
+      Some(value) => value,
+      None => panic!("🚨 Coordinate '{}' is outside of the {}x{} grid", coordinate, width, height)
+    }
+  }
+}
+
+impl<T> fmt::Display for Grid<T>
+where
+  T: Copy + Into<char>
+{
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for row in self.rows() {
+      let line: String = row.iter().map(|&value| value.into()).collect();
+      writeln!(f, "{}", line)?;
+    }
+
+    Ok(())
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sample() -> Grid<char> {
+    let lines: Vec<Vec<char>> = ["abc", "def"].iter().map(|line| line.chars().collect()).collect();
+    Grid::from_chars(&lines, |value| value)
+  }
+
+  #[test]
+  fn from_chars_is_row_major() {
+    let grid = sample();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Coordinate::new(2, 0)], 'c');
+    assert_eq!(grid[Coordinate::new(0, 1)], 'd');
+    assert_eq!(grid.get(&Coordinate::new(3, 0)), None);
+    assert_eq!(grid.get(&Coordinate::new(0, -1)), None);
+  }
+
+  #[test]
+  fn from_text_matches_from_chars() {
+    assert_eq!(Grid::from_text("abc\ndef\n", |value| value), sample());
+  }
+
+  #[test]
+  fn parse_text_locates_invalid_maps() {
+    let tile = |value: char| value.is_ascii_lowercase().then_some(value);
+    assert_eq!(Grid::parse_text("abc\ndef", tile).unwrap(), sample());
+
+    for (text, expected) in [("abc\nde", (2, 3)), ("abc\ndefg", (2, 4)), ("abc\ndEf", (2, 2)), ("", (1, 1))] {
+      match Grid::parse_text(text, tile) {
+        Err(InputError::Parse { line, column, .. }) => assert_eq!((line, column), expected, "🚨 Wrong location for {:?}", text),
+        _ => panic!("🚨 Expected {:?} to be rejected", text),
+      }
+    }
+  }
+
+  #[test]
+  #[should_panic]
+  fn from_chars_rejects_jagged_lines() {
+    let lines: Vec<Vec<char>> = vec![vec!['a', 'b'], vec!['c']];
+    Grid::from_chars(&lines, |value| value);
+  }
+
+  #[test]
+  fn neighbours_stay_in_bounds() {
+    let grid = sample();
+    let corner: Vec<Coordinate> = grid.neighbours_4(Coordinate::new(0, 0)).collect();
+    assert_eq!(corner, vec![Coordinate::new(1, 0), Coordinate::new(0, 1)]);
+    assert_eq!(grid.neighbours_8(Coordinate::new(1, 0)).count(), 5);
+  }
+
+  #[test]
+  fn rows_and_columns() {
+    let grid = sample();
+    assert_eq!(grid.row(1), &['d', 'e', 'f']);
+    assert_eq!(grid.column(1).copied().collect::<String>(), "be");
+    assert_eq!(grid.columns().count(), 3);
+  }
+
+  #[test]
+  fn transforms() {
+    let grid = sample();
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad\n");
+    assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+  }
+
+  #[test]
+  fn coordinate_3d_parses_with_spaces() {
+    assert_eq!("1,0,1".parse(), Ok(Coordinate3D::new(1, 0, 1)));
+    assert_eq!("19, 13, -30".parse(), Ok(Coordinate3D::new(19, 13, -30)));
+    assert!("1,0".parse::<Coordinate3D>().is_err());
+    assert!("1,a,0".parse::<Coordinate3D>().is_err());
+    assert_eq!(Coordinate3D::new(1, 2, 3).xy(), Coordinate::new(1, 2));
+  }
+
+  #[test]
+  fn directions_turn_and_move() {
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert!(Direction::ALL.iter().all(|&direction| direction.turn_left().turn_right() == direction));
+    assert!(Direction::ALL.iter().all(|&direction| direction.delta() + direction.opposite().delta() == Coordinate::new(0, 0)));
+    assert_eq!(Coordinate::new(2, 2) + Direction::Up.delta(), Coordinate::new(2, 1));
+  }
+
+  #[test]
+  fn from_rows_matches_from_chars() {
+    assert_eq!(Grid::from_rows(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]), sample());
+  }
+
+  #[test]
+  fn set_returns_previous_value() {
+    let mut grid = sample();
+    assert_eq!(grid.set(&Coordinate::new(1, 1), 'x'), Some('e'));
+    assert_eq!(grid.set(&Coordinate::new(5, 5), 'x'), None);
+    assert_eq!(grid.positions(|&value| value == 'x').collect::<Vec<_>>(), vec![Coordinate::new(1, 1)]);
+  }
+}
@@ -0,0 +1,9 @@
+pub mod error;
+pub mod graph;
+pub mod grid;
+pub mod math;
+pub mod range;
+pub mod random;
+pub mod rational;
+pub mod read;
+pub mod solver;
@@ -0,0 +1,109 @@
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+pub type ValueReading = i64;
+pub type ValueVariation = Vec<ValueReading>;
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+  match b {
+    0 => (1, 0, a),
+    _ => {
+      let (x, y, gcd) = extended_gcd(b, a % b);
+      (y, x - (a / b) * y, gcd)
+    }
+  }
+}
+
+/**
+ * Solves the system of congruences x = remainders[i] (mod moduli[i]).
+ *
+ * Returns '(x, lcm)' where 'x' is the smallest non-negative solution and 'lcm' the least common
+ * multiple of every modulus, or None when the congruences are incompatible.
+*/
+pub fn chinese_remainder_theorem(remainders: &[i64], moduli: &[i64]) -> Option<(i64, i64)> {
+  let mut solution: i64 = 0;
+  let mut modulus: i64 = 1;
+
+  for (&remainder, &other_modulus) in remainders.iter().zip(moduli.iter()) {
+    let (coefficient, _, gcd) = extended_gcd(modulus, other_modulus);
+    if (remainder - solution) % gcd != 0 { return None }
+
+    let step = (remainder - solution) / gcd * coefficient % (other_modulus / gcd);
+    solution += modulus * step;
+    modulus *= other_modulus / gcd;
+  }
+
+  Some(((solution % modulus + modulus) % modulus, modulus))
+}
+
+/// Finite differences of a sequence, one level per step, until a level made only of zeroes
+pub fn compute_variations_steps(variation: ValueVariation) -> Vec<ValueVariation> {
+  checked_variations_steps(variation).unwrap_or_else(|| panic!("🚨 Finite differences overflowed"))
+}
+
+/// Same as 'compute_variations_steps', but None when a difference does not fit a 'ValueReading'
+pub fn checked_variations_steps(variation: ValueVariation) -> Option<Vec<ValueVariation>> {
+  
+  let mut finished_computing = false;
+  let mut steps_variations: Vec<ValueVariation> = vec![variation];
+  while !finished_computing {
+
+    // Compute next step
+    let last_variation = steps_variations.last().unwrap();
+    let new_step: ValueVariation = last_variation.iter().zip(last_variation.iter().skip(1))
+      .map(|(&prev, &next)| next.checked_sub(prev))
+      .collect::<Option<_>>()?;
+
+    // Check if it should stop iteration
+    finished_computing = new_step.iter().all(|&value| value == 0);
+
+    // Update general tracker
+    steps_variations.push(new_step);
+  }
+
+  Some(steps_variations)
+}
+
+/// Levels left empty by a short sequence count as zero, so one or two readings extrapolate as a constant or a line
+pub fn estimate_next_value(variation_steps: &[ValueVariation]) -> ValueReading {
+  variation_steps.iter()
+    .map(|step| step.last().copied().unwrap_or(0))
+    .sum()
+}
+
+pub fn estimate_prev_value(variation_steps: &[ValueVariation]) -> ValueReading {
+  fn compute_signal(index: usize) -> i64 {
+    match index % 2 {
+      0 => 1,
+      1 => -1,
+      _ => panic!("🚨 Impossible for it to happen!")
+    }
+  }
+
+  variation_steps.iter().enumerate()
+    .map(|(index, step)| (compute_signal(index), step.first().copied().unwrap_or(0)))
+    .map(|(signal, step)| signal * step)
+    .sum()
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn coprime_moduli() {
+    assert_eq!(chinese_remainder_theorem(&[2, 3, 2], &[3, 5, 7]), Some((23, 105)));
+  }
+
+  #[test]
+  fn shared_factor_moduli() {
+    assert_eq!(chinese_remainder_theorem(&[0, 0], &[4, 6]), Some((0, 12)));
+    assert_eq!(chinese_remainder_theorem(&[1, 2], &[4, 6]), None);
+  }
+
+  #[test]
+  fn overflowing_differences() {
+    assert_eq!(checked_variations_steps(vec![1, 3, 5]), Some(vec![vec![1, 3, 5], vec![2, 2], vec![0]]));
+    assert_eq!(checked_variations_steps(vec![ValueReading::MIN, ValueReading::MAX]), None);
+  }
+}
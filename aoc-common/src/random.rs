@@ -0,0 +1,83 @@
+use std::fmt::Debug;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+/// Small xorshift generator, so generated inputs are the same on every run and a failing seed can be replayed
+pub struct Random {
+  state: u64
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+/// Checks an optimized solution against a naive reference on 'runs' generated cases, one seed each, naming the
+/// seed and the case of the first disagreement so it can be replayed
+pub fn differential<C, T, G, R, O>(runs: u64, generate: G, reference: R, optimized: O)
+where
+  C: Debug,
+  T: PartialEq + Debug,
+  G: Fn(&mut Random) -> C,
+  R: Fn(&C) -> T,
+  O: Fn(&C) -> T
+{
+  for seed in 1..=runs {
+    let case = generate(&mut Random::new(seed));
+    assert_eq!(optimized(&case), reference(&case), "🚨 Seed {} disagrees on {:?}", seed, case);
+  }
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Random {
+  pub fn new(seed: u64) -> Random {
+    Random { state: seed.max(1) }
+  }
+
+  fn next(&mut self) -> u64 {
+    self.state ^= self.state << 13;
+    self.state ^= self.state >> 7;
+    self.state ^= self.state << 17;
+    self.state
+  }
+
+  /// Uniform value in '0..bound'
+  pub fn below(&mut self, bound: u64) -> u64 {
+    self.next() % bound
+  }
+
+  /// Uniform value in 'low..=high'
+  pub fn range(&mut self, low: u64, high: u64) -> u64 {
+    low + self.below(high - low + 1)
+  }
+
+  pub fn pick<'a, T>(&mut self, values: &'a [T]) -> &'a T {
+    &values[self.below(values.len() as u64) as usize]
+  }
+
+  pub fn shuffle<T>(&mut self, values: &mut [T]) {
+    for index in (1..values.len()).rev() {
+      values.swap(index, self.below(index as u64 + 1) as usize);
+    }
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn seeds_are_replayed() {
+    let values: Vec<u64> = (0..5).map({ let mut random = Random::new(7); move |_| random.range(3, 9) }).collect();
+    let again: Vec<u64> = (0..5).map({ let mut random = Random::new(7); move |_| random.range(3, 9) }).collect();
+    assert_eq!(values, again);
+    assert!(values.iter().all(|value| (3..=9).contains(value)));
+  }
+
+  #[test]
+  fn differential_agrees_on_equal_solutions() {
+    differential(50, |random| random.range(1, 100), |&value| (1..=value).sum::<u64>(), |&value| value * (value + 1) / 2);
+  }
+
+  #[test]
+  #[should_panic]
+  fn differential_reports_disagreements() {
+    differential(50, |random| random.range(1, 100), |&value| value, |&value| value + 1);
+  }
+}
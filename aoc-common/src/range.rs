@@ -0,0 +1,81 @@
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+pub type RangeUnit = u64;
+
+/// Inclusive range of values, both 'range_start' and 'range_end' belonging to it
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct RangeItem {
+  pub range_start: RangeUnit,
+  pub range_end: RangeUnit,
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl RangeItem {
+  pub fn new(range_start: RangeUnit, range_end: RangeUnit) -> RangeItem {
+    RangeItem { range_start, range_end }
+  }
+
+  /// An inclusive range always holds its start, so it has no 'is_empty' to go along
+  #[allow(clippy::len_without_is_empty)]
+  pub fn len(&self) -> RangeUnit {
+    self.range_end - self.range_start + 1
+  }
+
+  pub fn contains(&self, value: RangeUnit) -> bool {
+    self.range_start <= value && value <= self.range_end
+  }
+
+  /// Splits the range into the parts lying before, inside and after 'window', each one None when empty
+  pub fn split(self, window: RangeItem) -> (Option<RangeItem>, Option<RangeItem>, Option<RangeItem>) {
+    let mut before_range: Option<RangeItem> = None;
+    let mut between_range: Option<RangeItem> = None;
+    let mut after_range: Option<RangeItem> = None;
+
+    // Check if there is a range out of scope before
+    if self.range_start < window.range_start {
+      before_range = Some(RangeItem::new(self.range_start, RangeUnit::min(window.range_start - 1, self.range_end)));
+    }
+
+    // Check if there is a range in between the window
+    let range_outside = self.range_end < window.range_start || self.range_start > window.range_end;
+    if !range_outside {
+      between_range = Some(RangeItem::new(
+        RangeUnit::max(window.range_start, self.range_start),
+        RangeUnit::min(window.range_end, self.range_end)));
+    }
+
+    // Check if there is a range out of scope after
+    if self.range_end > window.range_end {
+      after_range = Some(RangeItem::new(RangeUnit::max(window.range_end + 1, self.range_start), self.range_end));
+    }
+
+    (before_range, between_range, after_range)
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn split_around_window() {
+    let (before, between, after) = RangeItem::new(1, 10).split(RangeItem::new(4, 6));
+    assert_eq!(before, Some(RangeItem::new(1, 3)));
+    assert_eq!(between, Some(RangeItem::new(4, 6)));
+    assert_eq!(after, Some(RangeItem::new(7, 10)));
+  }
+
+  #[test]
+  fn split_outside_window() {
+    assert_eq!(RangeItem::new(1, 3).split(RangeItem::new(4, 6)), (Some(RangeItem::new(1, 3)), None, None));
+    assert_eq!(RangeItem::new(7, 9).split(RangeItem::new(4, 6)), (None, None, Some(RangeItem::new(7, 9))));
+    assert_eq!(RangeItem::new(5, 5).split(RangeItem::new(4, 6)), (None, Some(RangeItem::new(5, 5)), None));
+  }
+
+  #[test]
+  fn len_and_contains_are_inclusive() {
+    let range = RangeItem::new(4, 6);
+    assert_eq!(range.len(), 3);
+    assert!(range.contains(4) && range.contains(6) && !range.contains(7));
+  }
+}
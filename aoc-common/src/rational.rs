@@ -0,0 +1,183 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+pub type RationalUnit = i128;
+
+/// Exact fraction kept reduced, with a positive denominator. Every operation is checked and panics on
+/// overflow instead of silently losing precision
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Rational {
+  numerator: RationalUnit,
+  denominator: RationalUnit,
+}
+
+// ==================================================== CONSTANTS ====================================================
+const OVERFLOW: &str = "🚨 Rational arithmetic overflowed";
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+pub fn gcd(a: RationalUnit, b: RationalUnit) -> RationalUnit {
+  let (mut a, mut b) = (a.abs(), b.abs());
+  while b != 0 {
+    (a, b) = (b, a % b);
+  }
+  a
+}
+
+fn checked(value: Option<RationalUnit>) -> RationalUnit {
+  value.unwrap_or_else(|| panic!("{}", OVERFLOW))
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Rational {
+  pub const ZERO: Rational = Rational { numerator: 0, denominator: 1 };
+  pub const ONE: Rational = Rational { numerator: 1, denominator: 1 };
+
+  pub fn new(numerator: RationalUnit, denominator: RationalUnit) -> Rational {
+    if denominator == 0 {
+      panic!("🚨 Rational '{}/0' has no value", numerator);
+    }
+
+    let divisor = gcd(numerator, denominator) * denominator.signum();
+    Rational { numerator: numerator / divisor, denominator: denominator / divisor }
+  }
+
+  pub fn numerator(&self) -> RationalUnit {
+    self.numerator
+  }
+
+  pub fn denominator(&self) -> RationalUnit {
+    self.denominator
+  }
+
+  pub fn is_integer(&self) -> bool {
+    self.denominator == 1
+  }
+
+  /// Integer value, None when the fraction does not reduce to one
+  pub fn to_integer(&self) -> Option<RationalUnit> {
+    self.is_integer().then_some(self.numerator)
+  }
+}
+
+impl From<i64> for Rational {
+  fn from(value: i64) -> Self {
+    Rational { numerator: value as RationalUnit, denominator: 1 }
+  }
+}
+
+impl From<RationalUnit> for Rational {
+  fn from(value: RationalUnit) -> Self {
+    Rational { numerator: value, denominator: 1 }
+  }
+}
+
+impl Add for Rational {
+  type Output = Rational;
+
+  fn add(self, other: Rational) -> Rational {
+    let divisor = gcd(self.denominator, other.denominator);
+    let (left, right) = (self.denominator / divisor, other.denominator / divisor);
+    Rational::new(
+      checked(checked(self.numerator.checked_mul(right)).checked_add(checked(other.numerator.checked_mul(left)))),
+      checked(self.denominator.checked_mul(right)))
+  }
+}
+
+impl Sub for Rational {
+  type Output = Rational;
+
+  fn sub(self, other: Rational) -> Rational {
+    self + (-other)
+  }
+}
+
+impl Neg for Rational {
+  type Output = Rational;
+
+  fn neg(self) -> Rational {
+    Rational { numerator: checked(self.numerator.checked_neg()), denominator: self.denominator }
+  }
+}
+
+impl Mul for Rational {
+  type Output = Rational;
+
+  /// Cross-reduces first, so the products stay as small as the result allows
+  fn mul(self, other: Rational) -> Rational {
+    let first = gcd(self.numerator, other.denominator).max(1);
+    let second = gcd(other.numerator, self.denominator).max(1);
+    Rational::new(
+      checked((self.numerator / first).checked_mul(other.numerator / second)),
+      checked((self.denominator / second).checked_mul(other.denominator / first)))
+  }
+}
+
+impl Div for Rational {
+  type Output = Rational;
+
+  fn div(self, other: Rational) -> Rational {
+    if other.numerator == 0 {
+      panic!("🚨 Rational '{}' divided by zero", self);
+    }
+    self * Rational::new(other.denominator, other.numerator)
+  }
+}
+
+impl Ord for Rational {
+  fn cmp(&self, other: &Self) -> Ordering {
+    checked(self.numerator.checked_mul(other.denominator))
+      .cmp(&checked(other.numerator.checked_mul(self.denominator)))
+  }
+}
+
+impl PartialOrd for Rational {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl fmt::Display for Rational {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.denominator {
+      1 => write!(f, "{}", self.numerator),
+      _ => write!(f, "{}/{}", self.numerator, self.denominator),
+    }
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn fractions_are_reduced() {
+    assert_eq!(Rational::new(6, -4), Rational::new(-3, 2));
+    assert_eq!(Rational::new(6, -4).to_string(), "-3/2");
+    assert_eq!(Rational::new(0, -5), Rational::ZERO);
+  }
+
+  #[test]
+  fn arithmetic_is_exact() {
+    let third = Rational::new(1, 3);
+    assert_eq!(third + third + third, Rational::ONE);
+    assert_eq!(Rational::ONE - third, Rational::new(2, 3));
+    assert_eq!(third * Rational::from(6i64), Rational::from(2i64));
+    assert_eq!(Rational::new(3, 4) / Rational::new(-9, 8), Rational::new(-2, 3));
+    assert_eq!((Rational::new(7, 2) * Rational::from(2i64)).to_integer(), Some(7));
+  }
+
+  #[test]
+  fn ordering_compares_values() {
+    assert!(Rational::new(1, 3) < Rational::new(1, 2));
+    assert!(Rational::new(-1, 2) < Rational::new(-1, 3));
+  }
+
+  #[test]
+  #[should_panic]
+  fn overflow_panics() {
+    let _ = Rational::from(RationalUnit::MAX) + Rational::ONE;
+  }
+}
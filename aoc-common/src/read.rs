@@ -0,0 +1,207 @@
+use std::fs;
+use std::io::Read;
+
+use crate::error::InputError;
+
+/// Where a puzzle input comes from: a file, the standard input or a text already in memory
+#[derive(Clone, Copy, Debug)]
+pub enum Source<'a> {
+    File(&'a str),
+    Stdin,
+    Text(&'a str),
+}
+
+impl<'a> Source<'a> {
+
+    /// Follows the command line convention where '-' stands for the standard input
+    pub fn from_arg(argument: &'a str) -> Source<'a> {
+        match argument {
+            "-" => Source::Stdin,
+            path => Source::File(path),
+        }
+    }
+
+    /// Name used to locate errors, None for texts that do not come from anywhere
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Source::File(path) => Some(path),
+            Source::Stdin => Some("<stdin>"),
+            Source::Text(_) => None,
+        }
+    }
+}
+
+pub fn read_input(source: &Source) -> Result<String, InputError> {
+
+    let data: String = match source {
+        Source::File(path) => fs::read_to_string(path).map_err(|e| InputError::io(path, e))?,
+        Source::Stdin => {
+            let mut text = String::new();
+            std::io::stdin().read_to_string(&mut text).map_err(|e| InputError::io("<stdin>", e))?;
+            text
+        },
+        Source::Text(text) => text.to_string(),
+    };
+
+    Ok(data)
+}
+
+pub fn read_lines(source: &Source) -> Result<Vec<String>, InputError> {
+
+    let data: Vec<String> = read_input(source)?.lines()
+        .map(str::to_owned)
+        .collect();
+
+    Ok(data)
+}
+
+fn locate<T>(result: Result<T, InputError>, source: &Source) -> Result<T, InputError> {
+
+    match source.name() {
+        Some(name) => result.map_err(|e| e.in_file(name)),
+        None => result,
+    }
+}
+
+pub fn read_int_lines(source: &Source) -> Result<Vec<i64>, InputError> {
+
+    let data_string: String = read_input(source)?;
+    locate(parse_int_lines(&data_string), source)
+}
+
+pub fn read_list_int_lines(source: &Source, separator: &str) -> Result<Vec<Vec<i64>>, InputError> {
+
+    let data_string: String = read_input(source)?;
+    locate(parse_list_int_lines(&data_string, separator), source)
+}
+
+pub fn read_digits(source: &Source) -> Result<Vec<Vec<u32>>, InputError> {
+
+    let data_string: String = read_input(source)?;
+    locate(parse_digits(&data_string), source)
+}
+
+pub fn read_chars(source: &Source) -> Result<Vec<Vec<char>>, InputError> {
+
+    let data_string: String = read_input(source)?;
+    Ok(parse_chars(&data_string))
+}
+
+pub fn parse_int_lines(text: &str) -> Result<Vec<i64>, InputError> {
+
+    let data: Vec<i64> = text.lines().enumerate()
+        .map(|(line_index, line)| line.parse()
+            .map_err(|e| InputError::parse(line_index + 1, 1, line, e)))
+        .collect::<Result<_, _>>()?;
+
+    Ok(data)
+}
+
+pub fn parse_list_int_lines(text: &str, separator: &str) -> Result<Vec<Vec<i64>>, InputError> {
+
+    let data: Vec<Vec<i64>> = text.lines().enumerate()
+        .map(|(line_index, line)| {
+            let mut offset: usize = 0;
+            line.split(separator)
+                .map(|value| {
+                    let column = line[..offset].chars().count() + 1;
+                    offset += value.len() + separator.len();
+                    value.parse().map_err(|e| InputError::parse(line_index + 1, column, value, e))
+                })
+                .collect::<Result<_, _>>()
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(data)
+}
+
+pub fn parse_digits(text: &str) -> Result<Vec<Vec<u32>>, InputError> {
+
+    let data: Vec<Vec<u32>> = text.lines().enumerate()
+        .map(|(line_index, line)| line.chars().enumerate()
+            .map(|(column_index, characther)| characther.to_digit(10)
+                .ok_or_else(|| InputError::parse(line_index + 1, column_index + 1, &characther.to_string(), "not a digit")))
+            .collect::<Result<_, _>>())
+        .collect::<Result<_, _>>()?;
+
+    Ok(data)
+}
+
+/// Splits the text into the blocks separated by empty lines, each block keeping its own line breaks
+pub fn split_blocks(text: &str) -> Vec<&str> {
+    split_blocks_indexed(text).into_iter().map(|(_, block)| block).collect()
+}
+
+/// Same as 'split_blocks', each block paired with the index of its first line so errors can be located in the text
+pub fn split_blocks_indexed(text: &str) -> Vec<(usize, &str)> {
+
+    let mut blocks: Vec<(usize, &str)> = Vec::new();
+    let mut block_start: Option<(usize, usize)> = None;
+    let mut offset: usize = 0;
+    for (line_index, line) in text.split_inclusive('\n').enumerate() {
+        let is_empty = line.trim_end_matches(['\r', '\n']).is_empty();
+        match (is_empty, block_start) {
+            (true, Some((start_index, start))) => { blocks.push((start_index, &text[start..offset])); block_start = None; },
+            (false, None) => block_start = Some((line_index, offset)),
+            _ => {},
+        }
+        offset += line.len();
+    }
+    if let Some((start_index, start)) = block_start { blocks.push((start_index, &text[start..])) }
+
+    blocks
+}
+
+pub fn parse_chars(text: &str) -> Vec<Vec<char>> {
+
+    let data: Vec<Vec<char>> = text.lines()
+        .map(|line| line.chars().collect::<Vec<char>>())
+        .collect::<Vec<Vec<char>>>();
+
+    data
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_list_int_lines_reports_column() {
+        assert_eq!(parse_list_int_lines("1 -2 3", " ").unwrap(), vec![vec![1, -2, 3]]);
+
+        match parse_list_int_lines("1 2\n10 x 3", " ") {
+            Err(InputError::Parse { line, column, text, .. }) => assert_eq!((line, column, text.as_str()), (2, 4, "x")),
+            _ => panic!("🚨 Expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn parse_digits_rejects_letters() {
+        assert_eq!(parse_digits("12\n34").unwrap(), vec![vec![1, 2], vec![3, 4]]);
+        assert!(parse_digits("1a").is_err());
+    }
+
+    #[test]
+    fn missing_file_is_an_io_error() {
+        assert!(matches!(read_lines(&Source::File("does-not-exist.txt")), Err(InputError::Io { .. })));
+    }
+
+    #[test]
+    fn in_memory_text_is_not_located() {
+        assert_eq!(read_lines(&Source::Text("a\nb")).unwrap(), vec!["a", "b"]);
+        match read_int_lines(&Source::Text("1\nx")) {
+            Err(error) => assert_eq!(error.to_string(), "🚨 Invalid input 'x' at 2:1: invalid digit found in string"),
+            Ok(_) => panic!("🚨 Expected a parse error"),
+        }
+        assert!(Source::from_arg("-").name() == Some("<stdin>"));
+    }
+
+    #[test]
+    fn blocks_are_split_on_empty_lines() {
+        assert_eq!(split_blocks("a\nb\n\nc\n"), vec!["a\nb\n", "c\n"]);
+        assert_eq!(split_blocks("\r\na\r\n\r\n\r\nb"), vec!["a\r\n", "b"]);
+        assert!(split_blocks("").is_empty());
+        assert_eq!(split_blocks_indexed("\na\nb\n\n\nc"), vec![(1, "a\nb\n"), (5, "c")]);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::error::InputError;
+use crate::read;
+use crate::read::Source;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Part {
+  One,
+  Two
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Answer {
+  Unsigned(u64),
+  Signed(i64),
+  Text(String),
+  /// The input parsed but holds no answer for the part, with the reason why
+  Unsolved(String)
+}
+
+/// Common interface implemented by every day: parse the puzzle input once, then solve each part from it
+pub trait Solver {
+  type Input;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError>;
+  /// Writes the input back in the puzzle format, single spaced and without a trailing line break, so that
+  /// parsing what is written gives the same input again
+  fn render(input: &Self::Input) -> String;
+  fn part_one(input: &Self::Input) -> Answer;
+  fn part_two(input: &Self::Input) -> Answer;
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+/// Reads and parses the puzzle input, locating parse errors in the file it came from
+pub fn load<S: Solver>(source: &Source) -> Result<S::Input, InputError> {
+  let text = read::read_input(source)?;
+  match source.name() {
+    Some(name) => S::parse(&text).map_err(|error| error.in_file(name)),
+    None => S::parse(&text),
+  }
+}
+
+pub fn solve_part<S: Solver>(input: &S::Input, part: Part) -> Answer {
+  match part {
+    Part::One => S::part_one(input),
+    Part::Two => S::part_two(input),
+  }
+}
+
+pub fn solve<S: Solver>(source: &Source, part: Part) -> Result<Answer, InputError> {
+  let input = load::<S>(source)?;
+  Ok(solve_part::<S>(&input, part))
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Answer {
+  pub fn unsolved(reason: impl ToString) -> Self {
+    Answer::Unsolved(reason.to_string())
+  }
+}
+
+impl Part {
+  pub fn number(&self) -> u8 {
+    match self {
+      Part::One => 1,
+      Part::Two => 2,
+    }
+  }
+}
+
+impl std::fmt::Display for Part {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.number())
+  }
+}
+
+impl std::fmt::Display for Answer {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Answer::Unsigned(value) => write!(f, "{}", value),
+      Answer::Signed(value) => write!(f, "{}", value),
+      Answer::Text(value) => write!(f, "{}", value),
+      Answer::Unsolved(reason) => write!(f, "no answer ({})", reason),
+    }
+  }
+}
+
+macro_rules! impl_answer_from {
+  ($variant:ident, $target:ty, $($source:ty),+) => {
+    $(impl From<$source> for Answer {
+      fn from(value: $source) -> Self {
+        Answer::$variant(value as $target)
+      }
+    })+
+  };
+}
+
+impl_answer_from!(Unsigned, u64, u8, u16, u32, u64, usize);
+impl_answer_from!(Signed, i64, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+  fn from(value: String) -> Self {
+    Answer::Text(value)
+  }
+}
+
+impl From<&str> for Answer {
+  fn from(value: &str) -> Self {
+    Answer::Text(value.to_owned())
+  }
+}
@@ -0,0 +1,544 @@
+//! Seeded generators for puzzle inputs several times larger than the real ones. Every generator only
+//! produces inputs the solvers accept, keeping the same shape guarantees as the real inputs.
+
+use aoc::inputs::{grid_text, join_lines, CARDS, JUNK_PIPES, LOWERCASE, MIRRORS, SCHEMA_SYMBOLS};
+use aoc_common::grid::{Coordinate, CoordinateUnit};
+use aoc_common::random::Random;
+
+// ==================================================== CONSTANTS ====================================================
+const WRITTEN_DIGITS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+const NODE_LETTERS: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY";
+const GHOST_PERIODS: [usize; 6] = [43, 47, 53, 59, 61, 67];
+const COUNTER_PERIODS: [usize; 4] = [7919, 8039, 8111, 8179];
+const COUNTER_BITS: usize = 13;
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+/// Lowercase letter standing for a small index, module names being made of letters only
+fn letter(index: usize) -> char {
+  char::from(LOWERCASE[index])
+}
+
+/// Outline of a histogram with 'columns' bars of distinct neighbouring heights, as '(direction, length)'
+/// steps. It never crosses itself and always has '2 * columns + 2' steps, whatever the lengths are
+fn histogram_outline(random: &mut Random, columns: usize, max_length: u64) -> Vec<(char, u64)> {
+  let mut steps = Vec::with_capacity(2 * columns + 2);
+  let mut height = random.range(1, max_length);
+  let mut width = 0;
+  steps.push(('U', height));
+  for column in 0..columns {
+    let bar_width = random.range(1, max_length);
+    steps.push(('R', bar_width));
+    width += bar_width;
+    if column + 1 < columns {
+      let mut next_height = height;
+      while next_height == height {
+        next_height = random.range(1, max_length);
+      }
+      steps.push(if next_height > height { ('U', next_height - height) } else { ('D', height - next_height) });
+      height = next_height;
+    }
+  }
+  steps.push(('D', height));
+  steps.push(('L', width));
+  steps
+}
+
+/// Calibration lines mixing letters, digits and written digits, each holding at least one plain digit
+pub fn day_01(random: &mut Random) -> String {
+  join_lines((0..5000).map(|_| {
+    let length = random.range(10, 40) as usize;
+    let mut line = String::new();
+    while line.len() < length {
+      match random.below(10) {
+        0 => line.push_str(random.pick::<&str>(&WRITTEN_DIGITS)),
+        1 | 2 => line.push(char::from(b'1' + random.below(9) as u8)),
+        _ => line.push(*random.pick(LOWERCASE) as char),
+      }
+    }
+    let position = random.below(line.len() as u64 + 1) as usize;
+    line.insert(position, char::from(b'1' + random.below(9) as u8));
+    line
+  }))
+}
+
+pub fn day_02(random: &mut Random) -> String {
+  join_lines((1..=2000).map(|game| {
+    let records: Vec<String> = (0..random.range(3, 6))
+      .map(|_| {
+        let mut colours = vec!["red", "green", "blue"];
+        random.shuffle(&mut colours);
+        colours.truncate(random.range(1, 3) as usize);
+        colours.iter()
+          .map(|colour| format!("{} {}", random.range(1, 20), colour))
+          .collect::<Vec<String>>()
+          .join(", ")
+      })
+      .collect();
+    format!("Game {}: {}", game, records.join("; "))
+  }))
+}
+
+/// Schematic with numbers of up to three digits, always followed by a blank so they never merge
+pub fn day_03(random: &mut Random) -> String {
+  let size = 420;
+  join_lines((0..size).map(|_| {
+    let mut line = String::new();
+    while line.len() < size {
+      let remaining = size - line.len();
+      match random.below(100) {
+        chance if chance < 12 && remaining >= 4 => {
+          line.push_str(&random.range(1, 999).to_string());
+          line.push('.');
+        },
+        chance if chance < 17 => line.push(*random.pick(SCHEMA_SYMBOLS) as char),
+        _ => line.push('.'),
+      }
+    }
+    line.truncate(size);
+    line
+  }))
+}
+
+/// Cards win less than one card on average and never past the end, so the copy counts stay bounded
+pub fn day_04(random: &mut Random) -> String {
+  let cards = 2000;
+  join_lines((1..=cards).map(|card| {
+    let mut numbers: Vec<u64> = (1..=99).collect();
+    random.shuffle(&mut numbers);
+
+    let matches = match random.below(10) {
+      0..=6 => 0,
+      _ => random.range(1, 4) as usize,
+    }.min(cards - card);
+    let winning = &numbers[..10];
+    let drawn: Vec<u64> = winning[..matches].iter().chain(numbers[10..35 - matches].iter()).copied().collect();
+
+    let format_numbers = |numbers: &[u64]| numbers.iter()
+      .map(|number| format!("{:>2}", number))
+      .collect::<Vec<String>>()
+      .join(" ");
+    format!("Card {:>4}: {} | {}", card, format_numbers(winning), format_numbers(&drawn))
+  }))
+}
+
+/// Every map splits the id space into contiguous source ranges sent to random destinations
+pub fn day_05(random: &mut Random) -> String {
+  const ID_SPACE: u64 = 4_000_000_000;
+  let seeds: Vec<String> = (0..20)
+    .map(|_| format!("{} {}", random.below(ID_SPACE - 200_000_000), random.range(1, 200_000_000)))
+    .collect();
+  let mut sections = vec![format!("seeds: {}", seeds.join(" "))];
+
+  for stage in day_05::MAP_NAMES {
+    let mut cuts: Vec<u64> = (0..100).map(|_| random.below(ID_SPACE)).collect();
+    cuts.sort();
+    cuts.dedup();
+
+    let entries = cuts.windows(2)
+      .map(|cut| format!("{} {} {}", random.below(ID_SPACE), cut[0], cut[1] - cut[0]));
+    sections.push(join_lines(std::iter::once(format!("{} map:", stage)).chain(entries)));
+  }
+
+  sections.join("\n\n")
+}
+
+/// The kerned race concatenates every number, so only four races fit in a u64 like the real input
+pub fn day_06(random: &mut Random) -> String {
+  let races: Vec<(u64, u64)> = (0..4)
+    .map(|_| {
+      let time = random.range(40, 99);
+      (time, random.range(time * time / 8, time * time / 4 - time))
+    })
+    .collect();
+
+  let times: Vec<String> = races.iter().map(|(time, _)| format!("{:>5}", time)).collect();
+  let distances: Vec<String> = races.iter().map(|(_, distance)| format!("{:>5}", distance)).collect();
+  format!("Time:    {}\nDistance:{}", times.concat(), distances.concat())
+}
+
+/// Bids are kept small enough for the total winnings to fit the puzzle's u32
+pub fn day_07(random: &mut Random) -> String {
+  join_lines((0..2000).map(|_| {
+    let hand: String = (0..5).map(|_| *random.pick(CARDS) as char).collect();
+    format!("{} {}", hand, random.range(1, 1000))
+  }))
+}
+
+/// Each ghost walks a cycle whose length is the instruction length times a distinct prime, reaching its
+/// only end node once per cycle, like the real input. 'AAA' is the first ghost.
+pub fn day_08(random: &mut Random) -> String {
+  let instructions: String = (0..29).map(|_| if random.below(2) == 0 { 'L' } else { 'R' }).collect();
+  let mut lines: Vec<String> = Vec::new();
+  let mut next_name: usize = 0;
+  let mut intermediate_name = || {
+    let name: String = [next_name / 576, (next_name / 24) % 24, next_name % 24].iter()
+      .map(|&index| NODE_LETTERS[index] as char)
+      .collect();
+    next_name += 1;
+    name
+  };
+
+  for (ghost, period) in GHOST_PERIODS.iter().enumerate() {
+    let (start, end) = match ghost {
+      0 => ("AAA".to_owned(), "ZZZ".to_owned()),
+      _ => (format!("{}{}A", ghost, ghost), format!("{}{}Z", ghost, ghost)),
+    };
+    let cycle: Vec<String> = (1..period * instructions.len())
+      .map(|_| intermediate_name())
+      .chain(std::iter::once(end))
+      .collect();
+
+    lines.push(format!("{} = ({}, {})", start, cycle[0], cycle[0]));
+    for (index, node) in cycle.iter().enumerate() {
+      let next = &cycle[(index + 1) % cycle.len()];
+      lines.push(format!("{} = ({}, {})", node, next, next));
+    }
+  }
+
+  random.shuffle(&mut lines);
+  format!("{}\n\n{}", instructions, lines.join("\n"))
+}
+
+/// Readings sampled from integer polynomials, so the differences always reach zero
+pub fn day_09(random: &mut Random) -> String {
+  join_lines((0..1000).map(|_| {
+    let coefficients: Vec<i64> = (0..=random.range(1, 8)).map(|_| random.range(0, 6) as i64 - 3).collect();
+    (0..21_i64)
+      .map(|x| coefficients.iter().rev().fold(0, |value, coefficient| value * x + coefficient))
+      .map(|value| value.to_string())
+      .collect::<Vec<String>>()
+      .join(" ")
+  }))
+}
+
+/// A single comb-shaped loop with teeth every other column, surrounded by junk pipes
+pub fn day_10(random: &mut Random) -> String {
+  let (width, height): (CoordinateUnit, CoordinateUnit) = (200, 200);
+  let (top, bottom) = (2, height - 1);
+
+  let mut waypoints = vec![Coordinate::new(0, 0), Coordinate::new(width - 1, 0), Coordinate::new(width - 1, bottom)];
+  let mut tooth = width - 3;
+  while tooth >= 4 {
+    waypoints.extend([
+      Coordinate::new(tooth, bottom), Coordinate::new(tooth, top),
+      Coordinate::new(tooth - 2, top), Coordinate::new(tooth - 2, bottom)]);
+    tooth -= 4;
+  }
+  waypoints.extend([Coordinate::new(0, bottom), Coordinate::new(0, 0)]);
+
+  let mut path: Vec<Coordinate> = vec![waypoints[0]];
+  for segment in waypoints.windows(2) {
+    let step = Coordinate::new((segment[1].x - segment[0].x).signum(), (segment[1].y - segment[0].y).signum());
+    while *path.last().unwrap() != segment[1] {
+      path.push(*path.last().unwrap() + step);
+    }
+  }
+  path.pop();
+
+  let mut cells: Vec<Vec<char>> = (0..height)
+    .map(|_| (0..width).map(|_| *random.pick(JUNK_PIPES) as char).collect())
+    .collect();
+  for (index, &position) in path.iter().enumerate() {
+    let previous = path[(index + path.len() - 1) % path.len()] - position;
+    let next = path[(index + 1) % path.len()] - position;
+    let pipe = match (previous.min(next), previous.max(next)) {
+      (Coordinate::NORTH, Coordinate::SOUTH) => '|',
+      (Coordinate::WEST, Coordinate::EAST) => '-',
+      (Coordinate::NORTH, Coordinate::EAST) => 'L',
+      (Coordinate::WEST, Coordinate::NORTH) => 'J',
+      (Coordinate::WEST, Coordinate::SOUTH) => '7',
+      (Coordinate::SOUTH, Coordinate::EAST) => 'F',
+      unknown => panic!("🚨 Loop turns back on itself at '{}' ({:?})", position, unknown),
+    };
+    cells[position.y as usize][position.x as usize] = pipe;
+  }
+  // The start must connect to exactly two pipes, so clear the only junk cell touching it
+  cells[0][width as usize / 2] = 'S';
+  cells[1][width as usize / 2] = '.';
+
+  join_lines(cells.into_iter().map(|line| line.into_iter().collect()))
+}
+
+/// Sparse galaxies with a tenth of the rows and columns left empty
+pub fn day_11(random: &mut Random) -> String {
+  let size = 280;
+  let empty_rows: Vec<bool> = (0..size).map(|_| random.below(10) == 0).collect();
+  let empty_columns: Vec<bool> = (0..size).map(|_| random.below(10) == 0).collect();
+  grid_text(size, size, |x, y| match !empty_columns[x] && !empty_rows[y] && random.below(50) == 0 {
+    true => '#',
+    false => '.',
+  })
+}
+
+/// Records derived from a random arrangement, with some springs hidden behind '?'
+pub fn day_12(random: &mut Random) -> String {
+  join_lines((0..3000).map(|_| {
+    let mut springs: Vec<bool> = (0..random.range(8, 20)).map(|_| random.below(2) == 0).collect();
+    if !springs.contains(&true) { springs[0] = true }
+
+    let groups: Vec<String> = springs.split(|&damaged| !damaged)
+      .filter(|group| !group.is_empty())
+      .map(|group| group.len().to_string())
+      .collect();
+    let record: String = springs.iter()
+      .map(|&damaged| match (random.below(10) < 4, damaged) {
+        (true, _) => '?',
+        (false, true) => '#',
+        (false, false) => '.',
+      })
+      .collect();
+    format!("{} {}", record, groups.join(","))
+  }))
+}
+
+/**
+ * Every pattern mirrors its rows perfectly except for an extra last row, while its columns mirror with
+ * exactly one difference placed in that last row. So both parts always have a line to find.
+*/
+pub fn day_13(random: &mut Random) -> String {
+  let patterns: Vec<String> = (0..300)
+    .map(|_| {
+      let half_width = random.range(5, 8) as usize;
+      let half_height = random.range(4, 8) as usize;
+      let mut symmetric_row = || {
+        let half: Vec<char> = (0..half_width).map(|_| if random.below(2) == 0 { '#' } else { '.' }).collect();
+        half.iter().chain(half.iter().rev()).collect::<String>()
+      };
+
+      let top: Vec<String> = (0..half_height).map(|_| symmetric_row()).collect();
+      let mut last_row: Vec<char> = symmetric_row().chars().collect();
+      let smudge = random.below(2 * half_width as u64) as usize;
+      last_row[smudge] = if last_row[smudge] == '#' { '.' } else { '#' };
+
+      let rows = top.iter().chain(top.iter().rev()).cloned().chain(std::iter::once(last_row.into_iter().collect()));
+      join_lines(rows)
+    })
+    .collect();
+  patterns.join("\n\n")
+}
+
+pub fn day_14(random: &mut Random) -> String {
+  let size = 200;
+  grid_text(size, size, |_, _| match random.below(10) {
+    0 | 1 => 'O',
+    2 => '#',
+    _ => '.',
+  })
+}
+
+/// Steps drawn from a fixed pool of labels, so lenses get replaced and removed
+pub fn day_15(random: &mut Random) -> String {
+  let labels: Vec<String> = (0..2000)
+    .map(|_| (0..random.range(2, 6)).map(|_| *random.pick(LOWERCASE) as char).collect())
+    .collect();
+  (0..20000)
+    .map(|_| match random.below(3) {
+      0 => format!("{}-", random.pick(&labels)),
+      _ => format!("{}={}", random.pick(&labels), random.range(1, 9)),
+    })
+    .collect::<Vec<String>>()
+    .join(",")
+}
+
+pub fn day_16(random: &mut Random) -> String {
+  let size = 220;
+  grid_text(size, size, |_, _| match random.below(100) < 8 {
+    true => *random.pick(MIRRORS) as char,
+    false => '.',
+  })
+}
+
+pub fn day_17(random: &mut Random) -> String {
+  let size = 141;
+  grid_text(size, size, |_, _| char::from(b'1' + random.below(9) as u8))
+}
+
+/// Two independent histogram outlines of the same step count, the second one hidden in the colour codes
+pub fn day_18(random: &mut Random) -> String {
+  let columns = 1500;
+  let plain = histogram_outline(random, columns, 12);
+  // The closing step spans every bar, so it must still fit in the five hexadecimal digits
+  let hex = histogram_outline(random, columns, 0xfffff / columns as u64);
+  join_lines(plain.iter().zip(&hex).map(|(&(direction, length), &(hex_direction, hex_length))| {
+    let hex_digit = match hex_direction { 'R' => 0, 'D' => 1, 'L' => 2, _ => 3 };
+    format!("{} {} (#{:05x}{})", direction, length, hex_length, hex_digit)
+  }))
+}
+
+/// Workflows forming a tree rooted at 'in', so every part ends accepted or rejected
+pub fn day_19(random: &mut Random) -> String {
+  let workflow_count = 3000;
+  let mut lines: Vec<String> = Vec::new();
+  let mut pending: Vec<String> = vec!["in".to_owned()];
+  let mut created = 1;
+  let mut target = |random: &mut Random, pending: &mut Vec<String>| match created < workflow_count && random.below(3) > 0 {
+    true => {
+      let name: String = std::iter::once('w')
+        .chain([created / 676, (created / 26) % 26, created % 26].iter().map(|&index| LOWERCASE[index] as char))
+        .collect();
+      created += 1;
+      pending.push(name.clone());
+      name
+    },
+    false => if random.below(2) == 0 { "A".to_owned() } else { "R".to_owned() },
+  };
+
+  while let Some(name) = pending.pop() {
+    let rules: Vec<String> = (0..random.range(1, 4))
+      .map(|_| format!("{}{}{}:{}",
+        *random.pick(b"xmas") as char, *random.pick(b"<>") as char, random.range(1, 4000), target(random, &mut pending)))
+      .collect();
+    lines.push(format!("{}{{{},{}}}", name, rules.join(","), target(random, &mut pending)));
+  }
+  random.shuffle(&mut lines);
+
+  let parts = (0..2000).map(|_| format!("{{x={},m={},a={},s={}}}",
+    random.range(1, 4000), random.range(1, 4000), random.range(1, 4000), random.range(1, 4000)));
+  format!("{}\n\n{}", lines.join("\n"), join_lines(parts))
+}
+
+/// Binary counters of flip-flops, each reset by a conjunction once it reaches its period, as in the real inputs.
+/// The counter conjunctions feed the final machine through inverters and a single conjunction
+pub fn day_20(random: &mut Random) -> String {
+  let mut lines: Vec<String> = Vec::new();
+  let mut starts: Vec<String> = Vec::new();
+  for (counter, &period) in COUNTER_PERIODS.iter().enumerate() {
+    let bit_name = |bit: usize| format!("f{}{}", letter(counter), letter(bit));
+    let conjunction = format!("c{}", letter(counter));
+    let mut reset: Vec<String> = Vec::new();
+    for bit in 0..COUNTER_BITS {
+      let mut destinations: Vec<String> = Vec::new();
+      if bit + 1 < COUNTER_BITS { destinations.push(bit_name(bit + 1)) }
+      match (period >> bit) & 1 == 1 {
+        true => destinations.push(conjunction.clone()),
+        false => reset.push(bit_name(bit)),
+      }
+      random.shuffle(&mut destinations);
+      lines.push(format!("%{} -> {}", bit_name(bit), destinations.join(", ")));
+    }
+    reset.push(bit_name(0));
+    reset.push(format!("i{}", letter(counter)));
+    random.shuffle(&mut reset);
+    lines.push(format!("&{} -> {}", conjunction, reset.join(", ")));
+    lines.push(format!("&i{} -> hub", letter(counter)));
+    starts.push(bit_name(0));
+  }
+  lines.push("&hub -> rx".to_owned());
+  lines.push(format!("broadcaster -> {}", starts.join(", ")));
+  random.shuffle(&mut lines);
+  lines.join("\n")
+}
+
+/// Scattered rocks around a clear border and a clear row and column through the centred start, like the real maps
+pub fn day_21(random: &mut Random) -> String {
+  let size = 131;
+  let centre = size / 2;
+  grid_text(size, size, |x, y| match (x, y) {
+    _ if x == centre && y == centre => 'S',
+    _ if x == centre || y == centre || x == 0 || y == 0 || x == size - 1 || y == size - 1 => '.',
+    _ => if random.below(100) < 15 { '#' } else { '.' },
+  })
+}
+
+/// Straight bricks over a 10x10 footprint, each starting on its own level so none of them overlap before falling
+pub fn day_22(random: &mut Random) -> String {
+  let mut lines: Vec<String> = (0..6000u64)
+    .map(|index| {
+      let (x, y, z) = (random.below(10), random.below(10), 1 + 5 * index);
+      let length = random.below(4);
+      let (end_x, end_y, end_z) = match random.below(3) {
+        0 => ((x + length).min(9), y, z),
+        1 => (x, (y + length).min(9), z),
+        _ => (x, y, z + length),
+      };
+      format!("{},{},{}~{},{},{}", x, y, z, end_x, end_y, end_z)
+    })
+    .collect();
+  random.shuffle(&mut lines);
+  lines.join("\n")
+}
+
+/// Junctions on a 5x5 lattice of uneven spacing joined by straight corridors, every corridor sloping right or
+/// down next to its junctions. Real maps use a 6x6 lattice, which makes the longest hike too slow to bench
+pub fn day_23(random: &mut Random) -> String {
+  let lattice = 5;
+  let lines = |random: &mut Random| (0..lattice)
+    .scan(1, |line, _| { *line += random.range(20, 50) as usize; Some(*line) })
+    .collect::<Vec<usize>>();
+  let xs = lines(random);
+  let ys = lines(random);
+  let (width, height) = (xs[lattice - 1] + 2, ys[lattice - 1] + 2);
+
+  let mut map = vec![vec!['#'; width]; height];
+  (0..=ys[0]).for_each(|y| map[y][xs[0]] = '.');
+  (ys[lattice - 1]..height).for_each(|y| map[y][xs[lattice - 1]] = '.');
+  for (column, &x) in xs.iter().enumerate() {
+    for (row, &y) in ys.iter().enumerate() {
+      map[y][x] = '.';
+      if column + 1 < lattice {
+        (x + 1..xs[column + 1]).for_each(|corridor| map[y][corridor] = '.');
+        map[y][x + 1] = '>';
+        map[y][xs[column + 1] - 1] = '>';
+      }
+      if row + 1 < lattice {
+        (y + 1..ys[row + 1]).for_each(|corridor| map[corridor][x] = '.');
+        map[y + 1][x] = 'v';
+        map[ys[row + 1] - 1][x] = 'v';
+      }
+    }
+  }
+  join_lines(map.into_iter().map(|row| row.into_iter().collect()))
+}
+
+/// Hailstones all hit by one rock, each placed where the rock meets it at its own distinct collision time
+pub fn day_24(random: &mut Random) -> String {
+  let coordinate = |random: &mut Random| random.range(200000000000000, 400000000000000) as i64;
+  let speed = |random: &mut Random| random.range(0, 600) as i64 - 300;
+  let rock = [coordinate(random), coordinate(random), coordinate(random)];
+  let rock_velocity = [speed(random), speed(random), speed(random)];
+
+  let mut times: Vec<i64> = (0..1500).map(|index| 100000000000 + index * 500000000 + random.below(400000000) as i64).collect();
+  random.shuffle(&mut times);
+  join_lines(times.into_iter().map(|time| {
+    let velocity = [speed(random), speed(random), speed(random)];
+    let position: Vec<i64> = (0..3).map(|axis| rock[axis] + time * (rock_velocity[axis] - velocity[axis])).collect();
+    format!("{}, {}, {} @ {}, {}, {}", position[0], position[1], position[2], velocity[0], velocity[1], velocity[2])
+  }))
+}
+
+/// Two groups of components joined by exactly three wires. Every component links to the next two of its group
+/// and to a random one, so no three wires inside a group can split it
+pub fn day_25(random: &mut Random) -> String {
+  let group_size = 2000;
+  let name = |index: usize| -> String {
+    [index / 676, (index / 26) % 26, index % 26].iter().map(|&letter| LOWERCASE[letter] as char).collect()
+  };
+
+  let mut connections: Vec<Vec<usize>> = vec![Vec::new(); 2 * group_size];
+  for group in 0..2 {
+    let first = group * group_size;
+    for offset in 0..group_size {
+      connections[first + offset].push(first + (offset + 1) % group_size);
+      connections[first + offset].push(first + (offset + 2) % group_size);
+      let other = first + random.below(group_size as u64) as usize;
+      if other != first + offset && !connections[other].contains(&(first + offset))
+        && !connections[first + offset].contains(&other) {
+        connections[first + offset].push(other);
+      }
+    }
+  }
+  for _ in 0..3 {
+    let (from, to) = (random.below(group_size as u64) as usize, group_size + random.below(group_size as u64) as usize);
+    connections[from].push(to);
+  }
+
+  let mut lines: Vec<String> = connections.iter().enumerate()
+    .filter(|(_, connected)| !connected.is_empty())
+    .map(|(index, connected)| {
+      let connected: Vec<String> = connected.iter().map(|&other| name(other)).collect();
+      format!("{}: {}", name(index), connected.join(" "))
+    })
+    .collect();
+  random.shuffle(&mut lines);
+  lines.join("\n")
+}
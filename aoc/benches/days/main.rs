@@ -0,0 +1,83 @@
+//! Times parsing and each part separately for every day, both on the real input (when 'day-NN/input.txt'
+//! exists) and on a generated input several times larger:
+//!
+//! cargo bench -p aoc --bench days -- day-10
+
+mod generate;
+
+use std::fs;
+use std::hint::black_box;
+use std::path::PathBuf;
+
+use aoc::days::Day;
+use aoc_common::random::Random;
+use aoc_common::solver::Solver;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+type Generator = fn(&mut Random) -> String;
+
+// ==================================================== CONSTANTS ====================================================
+const SEED: u64 = 2023;
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+fn real_input(day: Day) -> Option<String> {
+  let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join(format!("day-{:02}", day)).join("input.txt");
+  fs::read_to_string(path).ok()
+}
+
+fn bench_input<S: Solver>(criterion: &mut Criterion, day: Day, label: &str, text: &str) {
+  let input = S::parse(text)
+    .unwrap_or_else(|error| panic!("🚨 Day {:02} could not parse its {} input: {}", day, label, error));
+
+  let mut group = criterion.benchmark_group(format!("day-{:02}/{}", day, label));
+  group.bench_function("parse", |bencher| bencher.iter(|| S::parse(black_box(text))));
+  group.bench_function("part-1", |bencher| bencher.iter(|| S::part_one(black_box(&input))));
+  group.bench_function("part-2", |bencher| bencher.iter(|| S::part_two(black_box(&input))));
+  group.finish();
+}
+
+fn bench_day<S: Solver>(criterion: &mut Criterion, day: Day, generator: Generator) {
+  if let Some(text) = real_input(day) {
+    bench_input::<S>(criterion, day, "real", &text);
+  }
+
+  let generated = generator(&mut Random::new(SEED + day as u64));
+  bench_input::<S>(criterion, day, "generated", &generated);
+}
+
+fn bench_days(criterion: &mut Criterion) {
+  bench_day::<day_01::Puzzle>(criterion, 1, generate::day_01);
+  bench_day::<day_02::Puzzle>(criterion, 2, generate::day_02);
+  bench_day::<day_03::Puzzle>(criterion, 3, generate::day_03);
+  bench_day::<day_04::Puzzle>(criterion, 4, generate::day_04);
+  bench_day::<day_05::Puzzle>(criterion, 5, generate::day_05);
+  bench_day::<day_06::Puzzle>(criterion, 6, generate::day_06);
+  bench_day::<day_07::Puzzle>(criterion, 7, generate::day_07);
+  bench_day::<day_08::Puzzle>(criterion, 8, generate::day_08);
+  bench_day::<day_09::Puzzle>(criterion, 9, generate::day_09);
+  bench_day::<day_10::Puzzle>(criterion, 10, generate::day_10);
+  bench_day::<day_11::Puzzle>(criterion, 11, generate::day_11);
+  bench_day::<day_12::Puzzle>(criterion, 12, generate::day_12);
+  bench_day::<day_13::Puzzle>(criterion, 13, generate::day_13);
+  bench_day::<day_14::Puzzle>(criterion, 14, generate::day_14);
+  bench_day::<day_15::Puzzle>(criterion, 15, generate::day_15);
+  bench_day::<day_16::Puzzle>(criterion, 16, generate::day_16);
+  bench_day::<day_17::Puzzle>(criterion, 17, generate::day_17);
+  bench_day::<day_18::Puzzle>(criterion, 18, generate::day_18);
+  bench_day::<day_19::Puzzle>(criterion, 19, generate::day_19);
+  bench_day::<day_20::Puzzle>(criterion, 20, generate::day_20);
+  bench_day::<day_21::Puzzle>(criterion, 21, generate::day_21);
+  bench_day::<day_22::Puzzle>(criterion, 22, generate::day_22);
+  bench_day::<day_23::Puzzle>(criterion, 23, generate::day_23);
+  bench_day::<day_24::Puzzle>(criterion, 24, generate::day_24);
+  bench_day::<day_25::Puzzle>(criterion, 25, generate::day_25);
+}
+
+// Some solvers take seconds on the generated inputs, so keep the number of samples low
+criterion_group! {
+  name = benches;
+  config = Criterion::default().sample_size(10);
+  targets = bench_days
+}
+criterion_main!(benches);
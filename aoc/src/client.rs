@@ -0,0 +1,165 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc_common::solver::Part;
+
+use crate::config::{Config, ENV_SESSION, YEAR};
+use crate::days::Day;
+use crate::ledger::{Ledger, Refusal};
+use crate::submission::Submission;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+/// Advent Of Code website client, authenticated with the session cookie of the configured user
+pub struct Client {
+  config: Config,
+  agent: ureq::Agent
+}
+
+/// Where a fetched input lives, and whether it had to be downloaded or was already cached
+#[derive(PartialEq, Eq, Debug)]
+pub struct FetchedInput {
+  pub path: PathBuf,
+  pub downloaded: bool
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum SubmitOutcome {
+  /// The ledger showed the answer is not worth sending
+  Refused(Refusal),
+  Sent(Submission),
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+  MissingSession,
+  Status {
+    url: String,
+    status: u16,
+    body: String
+  },
+  Transport {
+    url: String,
+    reason: String
+  },
+  Io {
+    file: PathBuf,
+    source: io::Error
+  },
+}
+
+// ==================================================== CONSTANTS ====================================================
+const TIMEOUT: Duration = Duration::from_secs(30);
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+/// Longest part of an error page worth showing
+const BODY_EXCERPT: usize = 200;
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Client {
+  pub fn new(config: Config) -> Client {
+    let agent = ureq::AgentBuilder::new().timeout(TIMEOUT).user_agent(USER_AGENT).build();
+    Client { config, agent }
+  }
+
+  pub fn config(&self) -> &Config {
+    &self.config
+  }
+
+  pub fn url(&self, path: &str) -> String {
+    format!("{}/{}{}", self.config.base_url, YEAR, path)
+  }
+
+  fn cookie(&self) -> Result<String, ClientError> {
+    let session = self.config.session.as_deref().ok_or(ClientError::MissingSession)?;
+    Ok(format!("session={}", session))
+  }
+
+  /// Gets the page, or posts the form to it, with the session cookie and reads the whole body of a successful
+  /// response
+  fn send(&self, path: &str, form: Option<&[(&str, &str)]>) -> Result<String, ClientError> {
+    let url = self.url(path);
+    let cookie = self.cookie()?;
+    let response = match form {
+      None => self.agent.get(&url).set("Cookie", &cookie).call(),
+      Some(form) => self.agent.post(&url).set("Cookie", &cookie).send_form(form),
+    };
+
+    match response {
+      Ok(response) => response.into_string()
+        .map_err(|error| ClientError::Transport { url, reason: error.to_string() }),
+      Err(ureq::Error::Status(status, response)) => {
+        let body = response.into_string().unwrap_or_default();
+        Err(ClientError::Status { url, status, body: body.trim().chars().take(BODY_EXCERPT).collect() })
+      },
+      Err(ureq::Error::Transport(transport)) => Err(ClientError::Transport { url, reason: transport.to_string() }),
+    }
+  }
+
+  pub fn download_input(&self, day: Day) -> Result<String, ClientError> {
+    self.send(&format!("/day/{}/input", day), None)
+  }
+
+  /// Downloads the input of the day into the cache unless it is already there, or always when 'refresh' is set
+  pub fn fetch_input(&self, day: Day, refresh: bool) -> Result<FetchedInput, ClientError> {
+    let path = self.config.cached_input(day).ok_or(ClientError::MissingSession)?;
+    if !refresh && path.is_file() {
+      return Ok(FetchedInput { path, downloaded: false });
+    }
+
+    let input = self.download_input(day)?;
+    if let Some(directory) = path.parent() {
+      fs::create_dir_all(directory).map_err(|source| ClientError::Io { file: directory.to_owned(), source })?;
+    }
+    fs::write(&path, input).map_err(|source| ClientError::Io { file: path.clone(), source })?;
+    Ok(FetchedInput { path, downloaded: true })
+  }
+
+  pub fn post_answer(&self, day: Day, part: Part, answer: &str) -> Result<Submission, ClientError> {
+    let level = part.number().to_string();
+    let html = self.send(&format!("/day/{}/answer", day), Some(&[("level", &level), ("answer", answer)]))?;
+    Ok(Submission::parse(&html))
+  }
+
+  /// Posts the answer unless the ledger refuses it, recording the response in the ledger
+  pub fn submit_answer(&self, ledger: &mut Ledger, day: Day, part: Part, answer: &str, now: u64)
+    -> Result<SubmitOutcome, ClientError> {
+
+    if let Err(refusal) = ledger.check(day, part, answer, now) {
+      return Ok(SubmitOutcome::Refused(refusal));
+    }
+
+    let submission = self.post_answer(day, part, answer)?;
+    ledger.record(day, part, answer, &submission, now);
+    Ok(SubmitOutcome::Sent(submission))
+  }
+}
+
+impl fmt::Display for ClientError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ClientError::MissingSession =>
+        write!(f, "🚨 No session token, set '{}' or 'session' in the config file", ENV_SESSION),
+      ClientError::Status { url, status: 400, .. } =>
+        write!(f, "🚨 Request to '{}' was refused, the session token may have expired", url),
+      ClientError::Status { url, status: 404, .. } =>
+        write!(f, "🚨 Nothing found at '{}', the puzzle may not be unlocked yet", url),
+      ClientError::Status { url, status, body } =>
+        write!(f, "🚨 Request to '{}' failed with status {}: {}", url, status, body),
+      ClientError::Transport { url, reason } =>
+        write!(f, "🚨 Could not reach '{}': {}", url, reason),
+      ClientError::Io { file, source } =>
+        write!(f, "🚨 Could not write '{}': {}", file.display(), source),
+    }
+  }
+}
+
+impl std::error::Error for ClientError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      ClientError::Io { source, .. } => Some(source),
+      _ => None,
+    }
+  }
+}
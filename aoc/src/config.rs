@@ -0,0 +1,201 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::days::Day;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+/// Settings of the Advent Of Code website client, each read from its environment variable first and from the
+/// config file otherwise
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Config {
+  pub session: Option<String>,
+  pub base_url: String,
+  pub cache_dir: PathBuf
+}
+
+/// Config file layout, every key being optional
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+  session: Option<String>,
+  base_url: Option<String>,
+  cache_dir: Option<PathBuf>
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+  Io {
+    file: PathBuf,
+    source: io::Error
+  },
+  Invalid {
+    file: PathBuf,
+    reason: String
+  },
+  NoCacheDir,
+}
+
+// ==================================================== CONSTANTS ====================================================
+pub const YEAR: u16 = 2023;
+pub const ENV_CONFIG: &str = "AOC_CONFIG";
+pub const ENV_SESSION: &str = "AOC_SESSION";
+pub const ENV_BASE_URL: &str = "AOC_BASE_URL";
+pub const ENV_CACHE_DIR: &str = "AOC_CACHE_DIR";
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const APPLICATION_DIR: &str = "aoc";
+const CONFIG_FILE: &str = "config.toml";
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+/// '$AOC_CONFIG' when set, 'aoc/config.toml' under the user config directory otherwise
+pub fn config_path() -> Option<PathBuf> {
+  env::var_os(ENV_CONFIG).map(PathBuf::from)
+    .or_else(|| dirs::config_dir().map(|directory| directory.join(APPLICATION_DIR).join(CONFIG_FILE)))
+}
+
+/// Directory name standing for the user the session token belongs to. Inputs and answers differ by user, and
+/// hashing the token with FNV-1a keeps it out of the path while giving the same name on every run
+fn user_key(session: &str) -> String {
+  let hash = session.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME));
+  format!("user-{:016x}", hash)
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Config {
+  /// Reads the settings from the environment and from the config file, a missing file leaving the defaults
+  pub fn load() -> Result<Config, ConfigError> {
+    let file = match config_path() {
+      None => None,
+      Some(path) => match fs::read_to_string(&path) {
+        Ok(text) => Some((path, text)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+        Err(source) => return Err(ConfigError::Io { file: path, source }),
+      },
+    };
+
+    let file = file.as_ref().map(|(path, text)| (path.clone(), text.as_str()));
+    Config::resolve(file, |name| env::var(name).ok().filter(|value| !value.trim().is_empty()))
+  }
+
+  /// Merges the config file text, if any, with the variables 'lookup' finds, which take precedence
+  pub fn resolve<F>(file: Option<(PathBuf, &str)>, lookup: F) -> Result<Config, ConfigError>
+    where F: Fn(&str) -> Option<String> {
+
+    let file: ConfigFile = match file {
+      None => ConfigFile::default(),
+      Some((path, text)) => toml::from_str(text)
+        .map_err(|error| ConfigError::Invalid { file: path, reason: error.message().to_owned() })?,
+    };
+
+    let session = lookup(ENV_SESSION).or(file.session).map(|session| session.trim().to_owned());
+    let base_url = lookup(ENV_BASE_URL).or(file.base_url)
+      .unwrap_or_else(|| DEFAULT_BASE_URL.to_owned())
+      .trim_end_matches('/').to_owned();
+    let cache_dir = lookup(ENV_CACHE_DIR).map(PathBuf::from).or(file.cache_dir)
+      .or_else(|| dirs::cache_dir().map(|directory| directory.join(APPLICATION_DIR)))
+      .ok_or(ConfigError::NoCacheDir)?;
+
+    Ok(Config { session, base_url, cache_dir })
+  }
+
+  /// Cache directory of the user the session token belongs to. None without a session token
+  pub fn user_dir(&self) -> Option<PathBuf> {
+    self.session.as_deref().map(|session| self.cache_dir.join(YEAR.to_string()).join(user_key(session)))
+  }
+
+  /// Where 'aoc fetch' keeps the input of the day for the user. None without a session token
+  pub fn cached_input(&self, day: Day) -> Option<PathBuf> {
+    self.user_dir().map(|directory| directory.join(format!("day-{:02}", day)).join("input.txt"))
+  }
+
+  /// Where 'aoc submit' records every answer the user sent. None without a session token
+  pub fn ledger(&self) -> Option<PathBuf> {
+    self.user_dir().map(|directory| directory.join("ledger.json"))
+  }
+}
+
+impl fmt::Display for ConfigError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ConfigError::Io { file, source } =>
+        write!(f, "🚨 Could not read the config file '{}': {}", file.display(), source),
+      ConfigError::Invalid { file, reason } =>
+        write!(f, "🚨 Invalid config file '{}': {}", file.display(), reason.trim()),
+      ConfigError::NoCacheDir =>
+        write!(f, "🚨 No user cache directory, set '{}' to choose one", ENV_CACHE_DIR),
+    }
+  }
+}
+
+impl std::error::Error for ConfigError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      ConfigError::Io { source, .. } => Some(source),
+      ConfigError::Invalid { .. } | ConfigError::NoCacheDir => None,
+    }
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const FILE: &str = "\
+session = \"from-file\"
+base_url = \"http://localhost:8080/\"
+cache_dir = \"/tmp/aoc-cache\"
+";
+
+  #[test]
+  fn file_settings_are_read() {
+    let config = Config::resolve(Some((PathBuf::from(CONFIG_FILE), FILE)), |_| None).unwrap();
+    assert_eq!(config, Config {
+      session: Some("from-file".to_owned()),
+      base_url: "http://localhost:8080".to_owned(),
+      cache_dir: PathBuf::from("/tmp/aoc-cache")
+    });
+  }
+
+  #[test]
+  fn environment_takes_precedence() {
+    let lookup = |name: &str| (name == ENV_SESSION).then(|| " from-env\n".to_owned());
+    let config = Config::resolve(Some((PathBuf::from(CONFIG_FILE), FILE)), lookup).unwrap();
+    assert_eq!(config.session.as_deref(), Some("from-env"));
+    assert_eq!(config.base_url, "http://localhost:8080");
+
+    let config = Config::resolve(None, |name| (name == ENV_CACHE_DIR).then(|| "cache".to_owned())).unwrap();
+    assert_eq!((config.session.as_deref(), config.base_url.as_str()), (None, DEFAULT_BASE_URL));
+    assert_eq!(config.cache_dir, PathBuf::from("cache"));
+    assert_eq!(config.cached_input(7), None);
+  }
+
+  #[test]
+  fn cache_is_kept_per_user() {
+    let config = |session: &str| Config::resolve(None, |name| match name {
+      ENV_SESSION => Some(session.to_owned()),
+      ENV_CACHE_DIR => Some("cache".to_owned()),
+      _ => None,
+    }).unwrap();
+
+    let (first, second) = (config("53616c7465645f5f"), config("6f746865725f7573"));
+    assert_eq!(first.cached_input(7), first.user_dir().map(|directory| directory.join("day-07/input.txt")));
+    assert_eq!(first.user_dir(), config("53616c7465645f5f").user_dir());
+    assert_ne!(first.user_dir(), second.user_dir());
+    assert_ne!(first.ledger(), second.ledger());
+    assert!(first.user_dir().unwrap().starts_with("cache/2023"));
+    assert!(!first.user_dir().unwrap().to_string_lossy().contains("53616c7465645f5f"));
+  }
+
+  #[test]
+  fn unknown_keys_are_reported() {
+    let error = Config::resolve(Some((PathBuf::from(CONFIG_FILE), "token = \"abc\"")), |_| None).unwrap_err();
+    assert!(matches!(error, ConfigError::Invalid { .. }));
+  }
+}
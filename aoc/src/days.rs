@@ -0,0 +1,127 @@
+
+use aoc_common::error::InputError;
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::{Answer, Part, Solver};
+
+use crate::config::Config;
+use crate::measure;
+use crate::measure::Measurement;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+pub type Day = u8;
+
+/// Entry points of a solved day, monomorphised for its 'Solver' so the table can hold every day side by side
+struct Solution {
+  run: fn(Day, String, &[Part]) -> Result<DayRun, InputError>,
+  round_trip: fn(&str) -> Result<String, InputError>,
+  solve_text: fn(&str) -> Result<[Answer; 2], InputError>,
+}
+
+pub struct PartRun {
+  pub part: Part,
+  pub answer: Answer,
+  pub measurement: Measurement
+}
+
+/// Answers of a single day together with how long and how much memory each step took
+pub struct DayRun {
+  pub day: Day,
+  pub parse: Measurement,
+  pub parts: Vec<PartRun>
+}
+
+// ==================================================== CONSTANTS ====================================================
+const SOLUTIONS: [(Day, Solution); 25] = [
+  (1, solution::<day_01::Puzzle>()),
+  (2, solution::<day_02::Puzzle>()),
+  (3, solution::<day_03::Puzzle>()),
+  (4, solution::<day_04::Puzzle>()),
+  (5, solution::<day_05::Puzzle>()),
+  (6, solution::<day_06::Puzzle>()),
+  (7, solution::<day_07::Puzzle>()),
+  (8, solution::<day_08::Puzzle>()),
+  (9, solution::<day_09::Puzzle>()),
+  (10, solution::<day_10::Puzzle>()),
+  (11, solution::<day_11::Puzzle>()),
+  (12, solution::<day_12::Puzzle>()),
+  (13, solution::<day_13::Puzzle>()),
+  (14, solution::<day_14::Puzzle>()),
+  (15, solution::<day_15::Puzzle>()),
+  (16, solution::<day_16::Puzzle>()),
+  (17, solution::<day_17::Puzzle>()),
+  (18, solution::<day_18::Puzzle>()),
+  (19, solution::<day_19::Puzzle>()),
+  (20, solution::<day_20::Puzzle>()),
+  (21, solution::<day_21::Puzzle>()),
+  (22, solution::<day_22::Puzzle>()),
+  (23, solution::<day_23::Puzzle>()),
+  (24, solution::<day_24::Puzzle>()),
+  (25, solution::<day_25::Puzzle>()),
+];
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+const fn solution<S: Solver>() -> Solution {
+  Solution { run: run_solver::<S>, round_trip: round_trip_solver::<S>, solve_text: solve_text_solver::<S> }
+}
+
+fn round_trip_solver<S: Solver>(text: &str) -> Result<String, InputError> {
+  S::parse(text).map(|input| S::render(&input))
+}
+
+fn solve_text_solver<S: Solver>(text: &str) -> Result<[Answer; 2], InputError> {
+  S::parse(text).map(|input| [S::part_one(&input), S::part_two(&input)])
+}
+
+fn run_solver<S: Solver>(day: Day, input: String, parts: &[Part]) -> Result<DayRun, InputError> {
+  let (parsed, parse) = measure::measure(|| solver::load::<S>(&Source::from_arg(&input)));
+  let parsed = parsed?;
+
+  let parts = parts.iter()
+    .map(|&part| {
+      let (answer, measurement) = measure::measure(|| solver::solve_part::<S>(&parsed, part));
+      PartRun { part, answer, measurement }
+    })
+    .collect();
+
+  Ok(DayRun { day, parse, parts })
+}
+
+pub fn available_days() -> Vec<Day> {
+  SOLUTIONS.iter().map(|&(day, _)| day).collect()
+}
+
+/// The input 'aoc fetch' cached for the day and the configured user when there is one, 'day-NN/input.txt'
+/// otherwise
+pub fn default_input(day: Day, config: Option<&Config>) -> String {
+  config.and_then(|config| config.cached_input(day))
+    .filter(|cached| cached.is_file())
+    .map_or_else(|| format!("day-{:02}/input.txt", day), |cached| cached.to_string_lossy().into_owned())
+}
+
+/// Parses the input, a file path or '-' for stdin, once and solves the given parts from it. None when the
+/// day has not been solved yet
+pub fn run(day: Day, input: String, parts: &[Part]) -> Option<Result<DayRun, InputError>> {
+  find_solution(day).map(|solution| (solution.run)(day, input, parts))
+}
+
+/// Parses the text and writes what was parsed back in the puzzle format. None when the day has not been solved
+pub fn round_trip(day: Day, text: &str) -> Option<Result<String, InputError>> {
+  find_solution(day).map(|solution| (solution.round_trip)(text))
+}
+
+/// Parses the text and solves both parts from it. None when the day has not been solved
+pub fn solve_text(day: Day, text: &str) -> Option<Result<[Answer; 2], InputError>> {
+  find_solution(day).map(|solution| (solution.solve_text)(text))
+}
+
+fn find_solution(day: Day) -> Option<&'static Solution> {
+  SOLUTIONS.iter()
+    .find(|&&(solution_day, _)| solution_day == day)
+    .map(|(_, solution)| solution)
+}
+
+pub fn solve(day: Day, part: Part, input: String) -> Option<Result<Answer, InputError>> {
+  run(day, input, &[part])
+    .map(|result| result.map(|mut day_run| day_run.parts.remove(0).answer))
+}
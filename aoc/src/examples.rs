@@ -0,0 +1,215 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::days::Day;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+/// Example of a part of the puzzle, as given by its statement
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Example {
+  pub input: String,
+  /// None when the statement does not emphasise any answer, such as part two on a page saved before solving
+  /// part one
+  pub answer: Option<String>
+}
+
+#[derive(Debug)]
+pub enum ExampleError {
+  Io {
+    file: PathBuf,
+    source: io::Error
+  },
+  NoExample(PathBuf),
+}
+
+// ==================================================== CONSTANTS ====================================================
+const ARTICLE_START: &str = "<article";
+const ARTICLE_END: &str = "</article>";
+const BLOCK_START: &str = "<pre><code>";
+const BLOCK_END: &str = "</code></pre>";
+/// Statements introduce their example inputs with 'For example:' or 'Here is an example', while later blocks
+/// show intermediate steps
+const EXAMPLE_MARKER: &str = "example";
+const ANSWER_MARKERS: [(&str, &str); 2] = [("<code><em>", "</em></code>"), ("<em><code>", "</code></em>")];
+const ENTITIES: [(&str, &str); 5] = [("&lt;", "<"), ("&gt;", ">"), ("&quot;", "\""), ("&#39;", "'"), ("&amp;", "&")];
+const FIXTURES_DIR: &str = "examples";
+const ANSWERS_FILE: &str = "answers.txt";
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+/// Every '<article>' of the page, part one first
+fn articles(html: &str) -> Vec<&str> {
+  let mut articles = Vec::new();
+  let mut rest = html;
+  while let Some(start) = rest.find(ARTICLE_START) {
+    let end = rest[start..].find(ARTICLE_END).map_or(rest.len(), |end| start + end);
+    articles.push(&rest[start..end]);
+    rest = &rest[end..];
+  }
+  articles
+}
+
+/// Text with the markup removed and the HTML entities decoded
+fn text(html: &str) -> String {
+  let mut text = String::with_capacity(html.len());
+  let mut in_tag = false;
+  for character in html.chars() {
+    match character {
+      '<' => in_tag = true,
+      '>' => in_tag = false,
+      _ if !in_tag => text.push(character),
+      _ => {},
+    }
+  }
+
+  ENTITIES.iter().fold(text, |text, (entity, character)| text.replace(entity, character))
+}
+
+/// Code blocks of the article, each with the prose leading to it
+fn blocks(article: &str) -> Vec<(&str, &str)> {
+  let mut blocks = Vec::new();
+  let mut rest = article;
+  while let Some(start) = rest.find(BLOCK_START) {
+    let Some(length) = rest[start..].find(BLOCK_END) else { break };
+    blocks.push((&rest[..start], &rest[start + BLOCK_START.len()..start + length]));
+    rest = &rest[start + length + BLOCK_END.len()..];
+  }
+  blocks
+}
+
+/// Input of the article example: the first block its prose calls an example, or else its first block
+fn example_input(article: &str) -> Option<String> {
+  let blocks = blocks(article);
+  blocks.iter()
+    .find(|(prose, _)| text(prose).to_lowercase().contains(EXAMPLE_MARKER))
+    .or(blocks.first())
+    .map(|(_, block)| text(block).trim_end_matches('\n').to_owned())
+}
+
+/// Statements emphasise the answer of their example last
+fn example_answer(article: &str) -> Option<String> {
+  ANSWER_MARKERS.iter()
+    .filter_map(|(start, end)| {
+      let position = article.rfind(start)?;
+      let length = article[position..].find(end)?;
+      Some((position, text(&article[position..position + length])))
+    })
+    .max_by_key(|&(position, _)| position)
+    .map(|(_, answer)| answer.trim().to_owned())
+}
+
+/// Examples of each part found in a saved puzzle page. Part two reuses the part one input when its own
+/// statement shows no new one
+pub fn extract(html: &str) -> Vec<Example> {
+  let mut examples: Vec<Example> = Vec::new();
+  for article in articles(html) {
+    let input = example_input(article).or_else(|| examples.last().map(|example| example.input.clone()));
+    if let Some(input) = input {
+      examples.push(Example { input, answer: example_answer(article) });
+    }
+  }
+  examples
+}
+
+pub fn fixtures_dir(root: &Path, day: Day) -> PathBuf {
+  root.join(format!("day-{:02}", day)).join(FIXTURES_DIR)
+}
+
+/// Path of the example input of a part, its answer being on the line of that part in 'answers.txt'
+pub fn fixture_input(directory: &Path, part: u8) -> PathBuf {
+  directory.join(format!("part-{}.txt", part))
+}
+
+pub fn fixture_answers(directory: &Path) -> PathBuf {
+  directory.join(ANSWERS_FILE)
+}
+
+/// Extracts the examples of the saved page and writes them as the fixtures of the day, returning the paths written
+pub fn write_fixtures(page: &Path, directory: &Path) -> Result<Vec<PathBuf>, ExampleError> {
+  let html = fs::read_to_string(page).map_err(|source| ExampleError::Io { file: page.to_owned(), source })?;
+  let examples = extract(&html);
+  if examples.is_empty() {
+    return Err(ExampleError::NoExample(page.to_owned()));
+  }
+
+  let io_error = |file: &Path, source: io::Error| ExampleError::Io { file: file.to_owned(), source };
+  fs::create_dir_all(directory).map_err(|source| io_error(directory, source))?;
+
+  let mut written = Vec::new();
+  for (part, example) in (1..).zip(&examples) {
+    let path = fixture_input(directory, part);
+    fs::write(&path, format!("{}\n", example.input)).map_err(|source| io_error(&path, source))?;
+    written.push(path);
+  }
+
+  let answers: Vec<&str> = examples.iter().map(|example| example.answer.as_deref().unwrap_or("")).collect();
+  let path = fixture_answers(directory);
+  fs::write(&path, answers.join("\n") + "\n").map_err(|source| io_error(&path, source))?;
+  written.push(path);
+  Ok(written)
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl fmt::Display for ExampleError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ExampleError::Io { file, source } =>
+        write!(f, "🚨 Could not access '{}': {}", file.display(), source),
+      ExampleError::NoExample(page) =>
+        write!(f, "🚨 No example found in '{}', is it a saved puzzle page?", page.display()),
+    }
+  }
+}
+
+impl std::error::Error for ExampleError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      ExampleError::Io { source, .. } => Some(source),
+      ExampleError::NoExample(_) => None,
+    }
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const PAGE: &str = "\
+<main>
+<article class=\"day-desc\"><h2>--- Day 0: Test ---</h2>
+<p>Some numbers are given, as in this example:</p>
+<pre><code>1 &lt; 2
+<em>3</em> &amp; 4
+</code></pre>
+<p>After one step, they become:</p>
+<pre><code>5 6
+</code></pre>
+<p>The <em>sum</em> here is <code><em>10</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>4242</code>.</p>
+<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>
+<p>Now multiply them: <em><code>24</code></em>.</p>
+</article>
+</main>";
+
+  #[test]
+  fn examples_and_answers_are_extracted() {
+    assert_eq!(extract(PAGE), vec![
+      Example { input: "1 < 2\n3 & 4".to_owned(), answer: Some("10".to_owned()) },
+      Example { input: "1 < 2\n3 & 4".to_owned(), answer: Some("24".to_owned()) },
+    ]);
+  }
+
+  #[test]
+  fn part_two_may_bring_its_own_example() {
+    let page = PAGE.replace("<p>Now multiply", "<p>For example:</p><pre><code>7\n</code></pre><p>Now multiply");
+    let examples = extract(&page);
+    assert_eq!(examples[1].input, "7");
+
+    let unsolved = &page[..page.find("<p>Your puzzle answer").unwrap()];
+    assert_eq!(extract(unsolved).len(), 1);
+    assert_eq!(extract("<article><p>No code <em>here</em></p></article>"), vec![]);
+  }
+}
@@ -0,0 +1,405 @@
+use aoc_common::random::Random;
+
+use crate::days::Day;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+/// Small change that turns a valid input into one its day must reject
+#[derive(Clone, Copy, Debug)]
+enum Corruption {
+  /// A character no puzzle input uses, at any position
+  Insert,
+  /// One occurrence of the pattern, falling back to 'Insert' when the pattern is not found
+  Replace(&'static str, &'static str),
+  /// The last character of a line dropped, leaving a map jagged. Maps are at least two lines high and two
+  /// characters wide, so the shortened line can never become the map width
+  ShortLine,
+}
+
+// ==================================================== CONSTANTS ====================================================
+pub const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+pub const SCHEMA_SYMBOLS: &[u8] = b"*#+$/@=%-&";
+pub const CARDS: &[u8] = b"23456789TJQKA";
+pub const JUNK_PIPES: &[u8] = b".|-LJ7F";
+pub const MIRRORS: &[u8] = b"/\\|-";
+const DIGITS: &[u8] = b"0123456789";
+const NODE_SYMBOLS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+const TRAIL_TILES: &[u8] = b"...#^v><";
+const FOREIGN_CHARS: [char; 4] = ['é', '€', 'ß', '→'];
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+pub fn join_lines(lines: impl IntoIterator<Item = String>) -> String {
+  lines.into_iter().collect::<Vec<String>>().join("\n")
+}
+
+/// Map of the given size, each tile being chosen from its coordinates
+pub fn grid_text(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> char) -> String {
+  join_lines((0..height).map(|y| (0..width).map(|x| cell(x, y)).collect::<String>()))
+}
+
+/// Map of random tiles, at least two lines high and two characters wide
+fn random_grid(random: &mut Random, tiles: &[u8]) -> String {
+  let (width, height) = (random.range(2, 8) as usize, random.range(2, 6) as usize);
+  grid_text(width, height, |_, _| *random.pick(tiles) as char)
+}
+
+fn random_word(random: &mut Random, symbols: &[u8], length: u64) -> String {
+  (0..length).map(|_| *random.pick(symbols) as char).collect()
+}
+
+/// Words of the given length, all different from each other and from the ones to avoid
+fn distinct_words(random: &mut Random, symbols: &[u8], length: u64, count: u64, avoid: &[&str]) -> Vec<String> {
+  let mut words: Vec<String> = Vec::new();
+  while words.len() < count as usize {
+    let word = random_word(random, symbols, length);
+    if !words.contains(&word) && !avoid.contains(&word.as_str()) { words.push(word) }
+  }
+  words
+}
+
+fn numbers(random: &mut Random, count: u64, low: u64, high: u64) -> Vec<String> {
+  (0..count).map(|_| random.range(low, high).to_string()).collect()
+}
+
+fn signed(random: &mut Random, bound: i64) -> i64 {
+  random.below(2 * bound as u64 + 1) as i64 - bound
+}
+
+fn day_01(random: &mut Random) -> String {
+  let symbols = [LOWERCASE, DIGITS].concat();
+  join_lines((0..random.range(1, 5)).map(|_| {
+    let length = random.range(1, 12);
+    random_word(random, &symbols, length)
+  }))
+}
+
+fn day_02(random: &mut Random) -> String {
+  join_lines((1..=random.range(1, 4)).map(|game| {
+    let records: Vec<String> = (0..random.range(1, 4))
+      .map(|_| {
+        let mut colours = vec!["red", "green", "blue"];
+        random.shuffle(&mut colours);
+        colours.truncate(random.range(1, 3) as usize);
+        colours.iter()
+          .map(|colour| format!("{} {}", random.range(1, 20), colour))
+          .collect::<Vec<String>>()
+          .join(", ")
+      })
+      .collect();
+    format!("Game {}: {}", game, records.join("; "))
+  }))
+}
+
+fn day_03(random: &mut Random) -> String {
+  let tiles = [b"......".as_slice(), DIGITS, SCHEMA_SYMBOLS].concat();
+  random_grid(random, &tiles)
+}
+
+fn day_04(random: &mut Random) -> String {
+  join_lines((1..=random.range(1, 5)).map(|card| {
+    let (winning_count, drawn_count) = (random.range(1, 5), random.range(1, 8));
+    let winning = numbers(random, winning_count, 1, 99);
+    let drawn = numbers(random, drawn_count, 1, 99);
+    format!("Card {}: {} | {}", card, winning.join(" "), drawn.join(" "))
+  }))
+}
+
+fn day_05(random: &mut Random) -> String {
+  let seeds: Vec<String> = (0..random.range(1, 3))
+    .map(|_| format!("{} {}", random.range(0, 100), random.range(1, 20)))
+    .collect();
+  let maps = day_05::MAP_NAMES.iter().map(|name| {
+    let entries = (0..random.range(0, 3))
+      .map(|_| format!("{} {} {}", random.range(0, 100), random.range(0, 100), random.range(1, 20)));
+    join_lines(std::iter::once(format!("{} map:", name)).chain(entries))
+  });
+
+  std::iter::once(format!("seeds: {}", seeds.join(" ")))
+    .chain(maps)
+    .collect::<Vec<String>>()
+    .join("\n\n")
+}
+
+fn day_06(random: &mut Random) -> String {
+  let races = random.range(1, 4);
+  let times = numbers(random, races, 1, 60);
+  let distances = numbers(random, races, 0, 999);
+  format!("Time: {}\nDistance: {}", times.join(" "), distances.join(" "))
+}
+
+fn day_07(random: &mut Random) -> String {
+  join_lines((0..random.range(1, 6)).map(|_| format!("{} {}", random_word(random, CARDS, 5), random.range(1, 999))))
+}
+
+fn day_08(random: &mut Random) -> String {
+  let instruction_count = random.range(1, 6);
+  let instructions = random_word(random, b"LR", instruction_count);
+  let node_count = random.range(1, 6);
+  let nodes = distinct_words(random, NODE_SYMBOLS, 3, node_count, &[]);
+  let network = nodes.iter()
+    .map(|node| format!("{} = ({}, {})", node, random.pick(&nodes), random.pick(&nodes)));
+
+  join_lines([instructions, String::new()].into_iter().chain(network))
+}
+
+fn day_09(random: &mut Random) -> String {
+  join_lines((0..random.range(1, 4)).map(|_| (0..random.range(1, 8))
+    .map(|_| signed(random, 50).to_string())
+    .collect::<Vec<String>>()
+    .join(" ")))
+}
+
+/// A rectangular loop among junk pipes, the junk next to the start cleared so only the loop connects to it
+fn day_10(random: &mut Random) -> String {
+  let (loop_width, loop_height) = (random.range(2, 5) as usize, random.range(2, 5) as usize);
+  let (left, top) = (random.range(0, 2) as usize, random.range(0, 2) as usize);
+  let (width, height) = (left + loop_width + random.range(0, 2) as usize, top + loop_height + random.range(0, 2) as usize);
+  let (right, bottom) = (left + loop_width - 1, top + loop_height - 1);
+
+  let on_loop = |x: usize, y: usize| (left..=right).contains(&x) && (top..=bottom).contains(&y)
+    && (x == left || x == right || y == top || y == bottom);
+  let loop_cells: Vec<(usize, usize)> = (0..height)
+    .flat_map(|y| (0..width).map(move |x| (x, y)))
+    .filter(|&(x, y)| on_loop(x, y))
+    .collect();
+  let start = *random.pick(&loop_cells);
+
+  grid_text(width, height, |x, y| match (x, y) {
+    _ if (x, y) == start => 'S',
+    _ if on_loop(x, y) => match (x == left, x == right, y == top, y == bottom) {
+      (true, _, true, _) => 'F',
+      (_, true, true, _) => '7',
+      (true, _, _, true) => 'L',
+      (_, true, _, true) => 'J',
+      (_, _, true, _) | (_, _, _, true) => '-',
+      _ => '|',
+    },
+    _ if x.abs_diff(start.0) + y.abs_diff(start.1) == 1 => '.',
+    _ => *random.pick(JUNK_PIPES) as char,
+  })
+}
+
+fn day_12(random: &mut Random) -> String {
+  join_lines((0..random.range(1, 5)).map(|_| {
+    let record_length = random.range(1, 10);
+    let hint_count = random.range(1, 4);
+    format!("{} {}", random_word(random, b".#?", record_length), numbers(random, hint_count, 1, 5).join(","))
+  }))
+}
+
+fn day_13(random: &mut Random) -> String {
+  (0..random.range(1, 3))
+    .map(|_| random_grid(random, b".#"))
+    .collect::<Vec<String>>()
+    .join("\n\n")
+}
+
+fn day_15(random: &mut Random) -> String {
+  (0..random.range(1, 8))
+    .map(|_| {
+      let label_length = random.range(1, 4);
+      let label = random_word(random, LOWERCASE, label_length);
+      match random.below(2) {
+        0 => format!("{}-", label),
+        _ => format!("{}={}", label, random.range(1, 9)),
+      }
+    })
+    .collect::<Vec<String>>()
+    .join(",")
+}
+
+fn day_18(random: &mut Random) -> String {
+  join_lines((0..random.range(1, 6)).map(|_| {
+    let hex = random_word(random, b"0123456789abcdef", 5);
+    format!("{} {} (#{}{})", *random.pick(b"UDRL") as char, random.range(1, 20), hex, random.range(0, 3))
+  }))
+}
+
+fn workflow_target(random: &mut Random, later: &[String]) -> String {
+  match random.below(3) {
+    0 if !later.is_empty() => random.pick(later).clone(),
+    0 | 1 => "A".to_owned(),
+    _ => "R".to_owned(),
+  }
+}
+
+/// Workflows only send parts to the ones listed after them, so they can never lead back to themselves
+fn day_19(random: &mut Random) -> String {
+  let extra_workflows = random.range(0, 4);
+  let names: Vec<String> = std::iter::once("in".to_owned())
+    .chain(distinct_words(random, LOWERCASE, 2, extra_workflows, &["in"]))
+    .collect();
+
+  let workflows: Vec<String> = names.iter().enumerate()
+    .map(|(index, name)| {
+      let later = &names[index + 1..];
+      let mut rules: Vec<String> = (0..random.range(0, 3))
+        .map(|_| format!("{}{}{}:{}",
+          *random.pick(b"xmas") as char, *random.pick(b"<>") as char, random.range(1, 4000), workflow_target(random, later)))
+        .collect();
+      rules.push(workflow_target(random, later));
+      format!("{}{{{}}}", name, rules.join(","))
+    })
+    .collect();
+  let parts = (0..random.range(1, 4))
+    .map(|_| format!("{{x={},m={},a={},s={}}}",
+      random.range(1, 4000), random.range(1, 4000), random.range(1, 4000), random.range(1, 4000)));
+
+  join_lines(workflows.into_iter().chain(std::iter::once(String::new())).chain(parts))
+}
+
+fn day_20(random: &mut Random) -> String {
+  let module_count = random.range(1, 5);
+  let modules = distinct_words(random, LOWERCASE, 2, module_count, &["rx"]);
+  let destinations: Vec<&str> = modules.iter().map(String::as_str).chain(["rx"]).collect();
+  let wiring = |random: &mut Random| (0..random.range(1, 3))
+    .map(|_| *random.pick(&destinations))
+    .collect::<Vec<&str>>()
+    .join(", ");
+
+  let broadcaster = format!("broadcaster -> {}", wiring(random));
+  let declared = modules.iter()
+    .map(|module| format!("{}{} -> {}", *random.pick(b"%&") as char, module, wiring(random)))
+    .collect::<Vec<String>>();
+  join_lines(std::iter::once(broadcaster).chain(declared))
+}
+
+fn day_21(random: &mut Random) -> String {
+  let (width, height) = (random.range(2, 8) as usize, random.range(2, 6) as usize);
+  let start = (random.below(width as u64) as usize, random.below(height as u64) as usize);
+  grid_text(width, height, |x, y| if (x, y) == start { 'S' } else { *random.pick(b"..#") as char })
+}
+
+fn day_22(random: &mut Random) -> String {
+  join_lines((0..random.range(1, 6)).map(|_| {
+    let low = [random.range(0, 9), random.range(0, 9), random.range(1, 50)];
+    let mut high = low;
+    high[random.below(3) as usize] += random.range(0, 3);
+    format!("{},{},{}~{},{},{}", low[0], low[1], low[2], high[0], high[1], high[2])
+  }))
+}
+
+/// Map whose first and last rows each hold a path tile for the hike to start and end on
+fn day_23(random: &mut Random) -> String {
+  let (width, height) = (random.range(2, 7) as usize, random.range(2, 7) as usize);
+  let (start, end) = (random.below(width as u64) as usize, random.below(width as u64) as usize);
+  grid_text(width, height, |x, y| match (x, y) {
+    _ if (y == 0 && x == start) || (y == height - 1 && x == end) => '.',
+    _ => *random.pick(TRAIL_TILES) as char,
+  })
+}
+
+fn day_24(random: &mut Random) -> String {
+  join_lines((0..random.range(1, 5)).map(|_| {
+    let position: Vec<String> = (0..3).map(|_| random.range(0, 100).to_string()).collect();
+    let velocity: Vec<String> = (0..3).map(|_| signed(random, 5).to_string()).collect();
+    format!("{} @ {}", position.join(", "), velocity.join(", "))
+  }))
+}
+
+fn day_25(random: &mut Random) -> String {
+  let component_count = random.range(2, 6);
+  let components = distinct_words(random, LOWERCASE, 3, component_count, &[]);
+  join_lines((0..random.range(1, 4)).map(|_| {
+    let wired: Vec<&str> = (0..random.range(1, 3)).map(|_| random.pick(&components).as_str()).collect();
+    format!("{}: {}", random.pick(&components), wired.join(" "))
+  }))
+}
+
+fn corruptions(day: Day) -> &'static [Corruption] {
+  use Corruption::*;
+  match day {
+    2 => &[Replace(" red", " pink"), Replace(", ", ",,"), Replace("Game ", "Gam "), Replace(": ", " ")],
+    3 | 11 | 13 | 14 | 16 | 17 | 23 => &[ShortLine],
+    4 => &[Replace(" | ", " / "), Replace("Card ", "Cart ")],
+    5 => &[Replace(" map:", " map"), Replace("seeds: ", "seeds "), Replace("-to-", "-from-")],
+    6 => &[Replace("Time:", "Tim:"), Replace("Distance:", "Distance")],
+    7 => &[Replace(" ", "")],
+    8 => &[Replace(" = (", " = "), Replace(", ", " ")],
+    9 => &[Replace(" ", "  ")],
+    10 | 21 => &[ShortLine, Replace("S", ".")],
+    12 => &[Replace(" ", ""), Replace(",", ",,")],
+    15 => &[Replace("=", "=="), Replace(",", ",,")],
+    18 => &[Replace("(#", "("), Replace(")", "")],
+    19 => &[Replace("{x=", "{y="), Replace(":", ";")],
+    20 => &[Replace(" -> ", " > "), Replace("broadcaster", "broadcast")],
+    22 => &[Replace("~", "-"), Replace(",", ";")],
+    24 => &[Replace(" @ ", " "), Replace(", ", " ")],
+    25 => &[Replace(": ", " ")],
+    _ => &[],
+  }
+}
+
+fn insert_foreign_char(text: &str, random: &mut Random) -> String {
+  let boundaries: Vec<usize> = text.char_indices().map(|(index, _)| index).chain([text.len()]).collect();
+  let at = *random.pick(&boundaries);
+  format!("{}{}{}", &text[..at], random.pick(&FOREIGN_CHARS), &text[at..])
+}
+
+fn replace_occurrence(text: &str, pattern: &str, replacement: &str, random: &mut Random) -> Option<String> {
+  let occurrences: Vec<usize> = text.match_indices(pattern).map(|(index, _)| index).collect();
+  if occurrences.is_empty() { return None }
+
+  let at = *random.pick(&occurrences);
+  Some(format!("{}{}{}", &text[..at], replacement, &text[at + pattern.len()..]))
+}
+
+fn shorten_line(text: &str, random: &mut Random) -> Option<String> {
+  let mut lines: Vec<&str> = text.split('\n').collect();
+  let candidates: Vec<usize> = (0..lines.len()).filter(|&index| lines[index].len() > 1).collect();
+  if candidates.is_empty() { return None }
+
+  let index = *random.pick(&candidates);
+  lines[index] = &lines[index][..lines[index].len() - 1];
+  Some(lines.join("\n"))
+}
+
+/// Small valid input of the day, written exactly as its 'Solver::render' would write it back, that both parts
+/// must solve or report as unsolved without panicking. None when the day has no generator
+pub fn valid(day: Day, random: &mut Random) -> Option<String> {
+  let generator: fn(&mut Random) -> String = match day {
+    1 => day_01,
+    2 => day_02,
+    3 => day_03,
+    4 => day_04,
+    5 => day_05,
+    6 => day_06,
+    7 => day_07,
+    8 => day_08,
+    9 => day_09,
+    10 => day_10,
+    11 => |random| random_grid(random, b"..#"),
+    12 => day_12,
+    13 => day_13,
+    14 => |random| random_grid(random, b".O#"),
+    15 => day_15,
+    16 => |random| random_grid(random, &[b".", MIRRORS].concat()),
+    17 => |random| random_grid(random, DIGITS),
+    18 => day_18,
+    19 => day_19,
+    20 => day_20,
+    21 => day_21,
+    22 => day_22,
+    23 => day_23,
+    24 => day_24,
+    25 => day_25,
+    _ => return None,
+  };
+  Some(generator(random))
+}
+
+/**
+ * Valid input of the day slightly corrupted so the day must reject it: either a character foreign to every
+ * puzzle inserted anywhere, or one of the changes known to break the format of that day. None when the day has
+ * no generator
+ */
+pub fn corrupted(day: Day, random: &mut Random) -> Option<String> {
+  let text = valid(day, random)?;
+  let corruptions: Vec<Corruption> = std::iter::once(Corruption::Insert).chain(corruptions(day).iter().copied()).collect();
+
+  let corrupted = match *random.pick(&corruptions) {
+    Corruption::Insert => None,
+    Corruption::Replace(pattern, replacement) => replace_occurrence(&text, pattern, replacement, random),
+    Corruption::ShortLine => shorten_line(&text, random),
+  };
+  Some(corrupted.unwrap_or_else(|| insert_foreign_char(&text, random)))
+}
@@ -0,0 +1,208 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc_common::solver::Part;
+use serde::{Deserialize, Serialize};
+
+use crate::days::Day;
+use crate::submission::{Submission, Verdict};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+/// Answer sent for a part, with what the website made of it
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug)]
+pub struct Attempt {
+  pub day: Day,
+  pub part: u8,
+  pub answer: String,
+  pub verdict: Verdict,
+  /// Seconds since the Unix epoch
+  pub submitted_at: u64,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub wait_seconds: Option<u64>
+}
+
+/// Every answer ever submitted, so answers known to be wrong are never sent twice
+#[derive(Serialize, Deserialize, PartialEq, Eq, Default, Debug)]
+pub struct Ledger {
+  attempts: Vec<Attempt>
+}
+
+/// Why an answer is not worth sending
+#[derive(PartialEq, Eq, Debug)]
+pub enum Refusal {
+  AlreadySolved(String),
+  KnownWrong(Verdict),
+  /// The answer is at least an answer already known to be too high
+  AboveBound(i128),
+  /// The answer is at most an answer already known to be too low
+  BelowBound(i128),
+  /// Seconds left before the website accepts another answer
+  Wait(u64),
+}
+
+#[derive(Debug)]
+pub enum LedgerError {
+  Io {
+    file: PathBuf,
+    source: io::Error
+  },
+  Invalid {
+    file: PathBuf,
+    reason: String
+  },
+}
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+pub fn unix_time() -> u64 {
+  SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Ledger {
+  /// Reads the ledger file, a missing one being an empty ledger
+  pub fn load(path: &Path) -> Result<Ledger, LedgerError> {
+    match fs::read_to_string(path) {
+      Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+      Err(source) => Err(LedgerError::Io { file: path.to_owned(), source }),
+      Ok(text) => serde_json::from_str(&text)
+        .map_err(|error| LedgerError::Invalid { file: path.to_owned(), reason: error.to_string() }),
+    }
+  }
+
+  pub fn save(&self, path: &Path) -> Result<(), LedgerError> {
+    if let Some(directory) = path.parent() {
+      fs::create_dir_all(directory).map_err(|source| LedgerError::Io { file: directory.to_owned(), source })?;
+    }
+    let text = serde_json::to_string_pretty(self).expect("🚨 Ledger could not be serialized");
+    fs::write(path, text + "\n").map_err(|source| LedgerError::Io { file: path.to_owned(), source })
+  }
+
+  pub fn attempts(&self, day: Day, part: Part) -> impl Iterator<Item = &Attempt> + '_ {
+    self.attempts.iter().filter(move |attempt| attempt.day == day && attempt.part == part.number())
+  }
+
+  pub fn record(&mut self, day: Day, part: Part, answer: &str, submission: &Submission, submitted_at: u64) {
+    self.attempts.push(Attempt {
+      day,
+      part: part.number(),
+      answer: answer.to_owned(),
+      verdict: submission.verdict,
+      submitted_at,
+      wait_seconds: submission.wait_seconds
+    });
+  }
+
+  /// Checks the answer against the previous attempts before anything is sent: the part must not be solved
+  /// yet, the answer must not be known to be wrong nor fall outside the bounds the too high and too low
+  /// answers set, and the wait the last response asked for must be over
+  pub fn check(&self, day: Day, part: Part, answer: &str, now: u64) -> Result<(), Refusal> {
+    let attempts: Vec<&Attempt> = self.attempts(day, part).collect();
+    if let Some(right) = attempts.iter().find(|attempt| attempt.verdict == Verdict::Right) {
+      return Err(Refusal::AlreadySolved(right.answer.clone()));
+    }
+    if let Some(wrong) = attempts.iter().find(|attempt| attempt.verdict.is_judged() && attempt.answer == answer) {
+      return Err(Refusal::KnownWrong(wrong.verdict));
+    }
+
+    if let Ok(value) = answer.parse::<i128>() {
+      let bound = |verdict: Verdict| attempts.iter()
+        .filter(move |attempt| attempt.verdict == verdict)
+        .filter_map(|attempt| attempt.answer.parse::<i128>().ok());
+      if let Some(high) = bound(Verdict::TooHigh).min().filter(|&high| value >= high) {
+        return Err(Refusal::AboveBound(high));
+      }
+      if let Some(low) = bound(Verdict::TooLow).max().filter(|&low| value <= low) {
+        return Err(Refusal::BelowBound(low));
+      }
+    }
+
+    let waiting_until = self.attempts.last()
+      .and_then(|attempt| Some(attempt.submitted_at + attempt.wait_seconds?));
+    match waiting_until {
+      Some(until) if until > now => Err(Refusal::Wait(until - now)),
+      _ => Ok(()),
+    }
+  }
+}
+
+impl fmt::Display for Refusal {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Refusal::AlreadySolved(answer) => write!(f, "🌟 Already solved with '{}'", answer),
+      Refusal::KnownWrong(verdict) => write!(f, "🚫 Answer already submitted and found {}", verdict),
+      Refusal::AboveBound(high) => write!(f, "🚫 Answer is not below '{}', already found too high", high),
+      Refusal::BelowBound(low) => write!(f, "🚫 Answer is not above '{}', already found too low", low),
+      Refusal::Wait(seconds) => write!(f, "⏳ Wait {}s before submitting another answer", seconds),
+    }
+  }
+}
+
+impl fmt::Display for LedgerError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      LedgerError::Io { file, source } =>
+        write!(f, "🚨 Could not access the answer ledger '{}': {}", file.display(), source),
+      LedgerError::Invalid { file, reason } =>
+        write!(f, "🚨 Invalid answer ledger '{}': {}", file.display(), reason),
+    }
+  }
+}
+
+impl std::error::Error for LedgerError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      LedgerError::Io { source, .. } => Some(source),
+      LedgerError::Invalid { .. } => None,
+    }
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn submission(verdict: Verdict, wait_seconds: Option<u64>) -> Submission {
+    Submission { verdict, wait_seconds, message: String::new() }
+  }
+
+  #[test]
+  fn wrong_answers_and_bounds_are_refused() {
+    let mut ledger = Ledger::default();
+    ledger.record(5, Part::One, "100", &submission(Verdict::TooHigh, Some(60)), 1000);
+    ledger.record(5, Part::One, "20", &submission(Verdict::TooLow, Some(60)), 1100);
+    ledger.record(5, Part::One, "abc", &submission(Verdict::Wrong, Some(300)), 1200);
+
+    assert_eq!(ledger.check(5, Part::One, "abc", 2000), Err(Refusal::KnownWrong(Verdict::Wrong)));
+    assert_eq!(ledger.check(5, Part::One, "150", 2000), Err(Refusal::AboveBound(100)));
+    assert_eq!(ledger.check(5, Part::One, "-3", 2000), Err(Refusal::BelowBound(20)));
+    assert_eq!(ledger.check(5, Part::One, "50", 1400), Err(Refusal::Wait(100)));
+    assert_eq!(ledger.check(5, Part::One, "50", 2000), Ok(()));
+    assert_eq!(ledger.check(5, Part::Two, "150", 2000), Ok(()));
+  }
+
+  #[test]
+  fn solved_parts_are_refused() {
+    let mut ledger = Ledger::default();
+    ledger.record(5, Part::One, "42", &submission(Verdict::Wait, Some(30)), 1000);
+    assert_eq!(ledger.check(5, Part::One, "42", 1010), Err(Refusal::Wait(20)));
+
+    ledger.record(5, Part::One, "42", &submission(Verdict::Right, None), 1030);
+    assert_eq!(ledger.check(5, Part::One, "43", 1040), Err(Refusal::AlreadySolved("42".to_owned())));
+  }
+
+  #[test]
+  fn ledger_round_trips() {
+    let path = std::env::temp_dir().join(format!("aoc-ledger-{}", std::process::id())).join("ledger.json");
+    assert_eq!(Ledger::load(&path).unwrap(), Ledger::default());
+
+    let mut ledger = Ledger::default();
+    ledger.record(1, Part::Two, "281", &submission(Verdict::TooLow, Some(60)), 1000);
+    ledger.save(&path).unwrap();
+    assert_eq!(Ledger::load(&path).unwrap(), ledger);
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+  }
+}
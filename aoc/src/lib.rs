@@ -0,0 +1,10 @@
+pub mod client;
+pub mod config;
+pub mod days;
+pub mod examples;
+pub mod inputs;
+pub mod ledger;
+pub mod measure;
+pub mod report;
+pub mod scaffold;
+pub mod submission;
@@ -0,0 +1,291 @@
+// Imports
+use std::env;
+use std::path::{Path, PathBuf};
+
+use aoc::client::{Client, ClientError, SubmitOutcome};
+use aoc::config::Config;
+use aoc::days;
+use aoc::days::{Day, DayRun};
+use aoc::examples;
+use aoc::ledger;
+use aoc::ledger::{Ledger, Refusal};
+use aoc::measure::CountingAllocator;
+use aoc::report;
+use aoc::scaffold;
+use aoc::submission::Verdict;
+use aoc_common::error::InputError;
+use aoc_common::read;
+use aoc_common::read::Source;
+use aoc_common::solver::{Answer, Part};
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "🎅 Runner for the Advent Of Code 2023 solutions")]
+struct Cli {
+  #[command(subcommand)]
+  command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+  /// Run the solution of a single day, or of every day in order
+  Run(RunArgs),
+  /// Download the puzzle input of a day into the user cache, where 'run' finds it
+  Fetch(FetchArgs),
+  /// Submit the answer of a part, unless the answer ledger shows it is not worth sending
+  Submit(SubmitArgs),
+  /// Extract the examples of a saved puzzle page into the test fixtures of the day
+  Examples(ExamplesArgs),
+  /// Generate a new day crate and register it in the workspace and the runner
+  New(NewArgs),
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+  /// Aligned table meant to be read by people
+  Table,
+  /// Array of JSON records meant to be read by scripts
+  Json,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("selection").required(true).args(["day", "all"])))]
+struct RunArgs {
+  /// Day to run
+  #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+  day: Option<Day>,
+  /// Part to run, both parts are run when omitted
+  #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+  part: Option<u8>,
+  /// Puzzle input file or '-' for stdin, defaults to the input fetched for the day or else to 'day-NN/input.txt'
+  #[arg(long, requires = "day")]
+  input: Option<String>,
+  /// Run every available day in order
+  #[arg(long)]
+  all: bool,
+  /// How the results are printed
+  #[arg(long, value_enum, default_value_t = Format::Table)]
+  format: Format,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+  /// Day to download the input of
+  #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+  day: Day,
+  /// Download the input again even when it is already cached
+  #[arg(long)]
+  refresh: bool,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+  /// Day to submit the answer of
+  #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+  day: Day,
+  /// Part to submit the answer of
+  #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+  part: u8,
+  /// Answer to submit, computed by running the day when omitted
+  #[arg(long)]
+  answer: Option<String>,
+  /// Puzzle input file or '-' for stdin the answer is computed from, found the same way as by 'run' when omitted
+  #[arg(long, conflicts_with = "answer")]
+  input: Option<String>,
+}
+
+#[derive(Args)]
+struct ExamplesArgs {
+  /// Day the puzzle page belongs to
+  #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+  day: Day,
+  /// Puzzle page saved from the browser
+  #[arg(long)]
+  page: PathBuf,
+}
+
+#[derive(Args)]
+struct NewArgs {
+  /// Day to generate
+  #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+  day: Day,
+  /// File holding the example input of the puzzle statement or '-' for stdin, left empty when omitted
+  #[arg(long)]
+  example: Option<String>,
+}
+
+fn load_config() -> Config {
+  Config::load().unwrap_or_else(|error| {
+    eprintln!("{}", error);
+    std::process::exit(1);
+  })
+}
+
+fn run(args: RunArgs) {
+  let days: Vec<Day> = match args.day {
+    Some(day) => vec![day],
+    None => days::available_days(),
+  };
+  let parts: Vec<Part> = match args.part {
+    Some(1) => vec![Part::One],
+    Some(_) => vec![Part::Two],
+    None => vec![Part::One, Part::Two],
+  };
+
+  let config = args.input.is_none().then(load_config);
+  let mut runs: Vec<DayRun> = Vec::new();
+  let mut errors: Vec<InputError> = Vec::new();
+  for day in days {
+    let input = args.input.clone().unwrap_or_else(|| days::default_input(day, config.as_ref()));
+    if args.all && !Path::new(&input).is_file() {
+      eprintln!("⚠️ Skipping day {}: no input at '{}', 'aoc fetch --day {}' downloads it", day, input, day);
+      continue;
+    }
+    match days::run(day, input, &parts) {
+      None => {
+        eprintln!("🚨 Day '{}' has not been solved yet", day);
+        std::process::exit(1);
+      },
+      Some(Err(error)) => errors.push(error),
+      Some(Ok(day_run)) => runs.push(day_run),
+    }
+  }
+
+  // Days that did run are still reported when others could not
+  if !runs.is_empty() || errors.is_empty() {
+    match args.format {
+      Format::Table => println!("{}", report::table(&runs)),
+      Format::Json => println!("{}", report::json(&runs)),
+    }
+  }
+  for error in &errors {
+    eprintln!("{}", error);
+  }
+  if !errors.is_empty() {
+    std::process::exit(1);
+  }
+}
+
+fn fetch(args: FetchArgs) {
+  let client = Client::new(load_config());
+  match client.fetch_input(args.day, args.refresh) {
+    Err(error) => {
+      eprintln!("{}", error);
+      std::process::exit(1);
+    },
+    Ok(fetched) if fetched.downloaded =>
+      println!("📥 Downloaded the day {} input to '{}'", args.day, fetched.path.display()),
+    Ok(fetched) =>
+      println!("📦 Day {} input already cached at '{}'", args.day, fetched.path.display()),
+  }
+}
+
+fn submit(args: SubmitArgs) {
+  let client = Client::new(load_config());
+  let part = if args.part == 1 { Part::One } else { Part::Two };
+  let answer = args.answer.unwrap_or_else(|| {
+    let input = args.input.unwrap_or_else(|| days::default_input(args.day, Some(client.config())));
+    match days::solve(args.day, part, input) {
+      None => {
+        eprintln!("🚨 Day '{}' has not been solved yet", args.day);
+        std::process::exit(1);
+      },
+      Some(Err(error)) => error.report(),
+      Some(Ok(Answer::Unsolved(reason))) => {
+        eprintln!("🚨 Day {} part {} has no answer to submit: {}", args.day, part, reason);
+        std::process::exit(1);
+      },
+      Some(Ok(answer)) => answer.to_string(),
+    }
+  });
+
+  let Some(ledger_path) = client.config().ledger() else {
+    eprintln!("{}", ClientError::MissingSession);
+    std::process::exit(1);
+  };
+  let mut ledger = Ledger::load(&ledger_path).unwrap_or_else(|error| {
+    eprintln!("{}", error);
+    std::process::exit(1);
+  });
+  let outcome = client.submit_answer(&mut ledger, args.day, part, &answer, ledger::unix_time())
+    .unwrap_or_else(|error| {
+      eprintln!("{}", error);
+      std::process::exit(1);
+    });
+  if let Err(error) = ledger.save(&ledger_path) {
+    eprintln!("{}", error);
+  }
+
+  let solved = match outcome {
+    SubmitOutcome::Refused(refusal) => {
+      println!("{}", refusal);
+      refusal == Refusal::AlreadySolved(answer)
+    },
+    SubmitOutcome::Sent(submission) => {
+      let icon = match submission.verdict {
+        Verdict::Right => "🌟",
+        Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow => "❌",
+        Verdict::Wait => "⏳",
+        Verdict::AlreadySolved | Verdict::Unknown => "❔",
+      };
+      println!("{} Answer '{}' is {}: {}", icon, answer, submission.verdict, submission.message);
+      submission.verdict == Verdict::Right
+    },
+  };
+  if !solved {
+    std::process::exit(1);
+  }
+}
+
+fn extract_examples(args: ExamplesArgs) {
+  let directory = examples::fixtures_dir(Path::new(""), args.day);
+  match examples::write_fixtures(&args.page, &directory) {
+    Err(error) => {
+      eprintln!("{}", error);
+      std::process::exit(1);
+    },
+    Ok(files) => {
+      for file in files {
+        println!("📝 {}", file.display());
+      }
+    },
+  }
+}
+
+fn new(args: NewArgs) {
+  let example = match &args.example {
+    Some(path) => read::read_input(&Source::from_arg(path)).unwrap_or_else(|error| error.report()),
+    None => String::new(),
+  };
+  let root = env::current_dir().unwrap_or_else(|error| {
+    eprintln!("🚨 Could not find the current directory: {}", error);
+    std::process::exit(1);
+  });
+
+  match scaffold::new_day(&root, args.day, &example) {
+    Err(error) => {
+      eprintln!("{}", error);
+      std::process::exit(1);
+    },
+    Ok(files) => {
+      for file in files {
+        println!("📝 {}", file.strip_prefix(&root).unwrap_or(&file).display());
+      }
+    },
+  }
+}
+
+fn main() {
+
+  let cli = Cli::parse();
+  match cli.command {
+    Command::Run(args) => run(args),
+    Command::Fetch(args) => fetch(args),
+    Command::Submit(args) => submit(args),
+    Command::Examples(args) => extract_examples(args),
+    Command::New(args) => new(args),
+  }
+}
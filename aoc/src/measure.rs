@@ -0,0 +1,74 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+/// System allocator wrapper keeping track of the allocated bytes, install it with '#[global_allocator]'
+pub struct CountingAllocator;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Measurement {
+  pub elapsed: Duration,
+  pub peak_bytes: usize
+}
+
+// ==================================================== CONSTANTS ====================================================
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+fn record_allocation(size: usize) {
+  let allocated = ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+  PEAK_BYTES.fetch_max(allocated, Ordering::Relaxed);
+}
+
+fn record_deallocation(size: usize) {
+  ALLOCATED_BYTES.fetch_sub(size, Ordering::Relaxed);
+}
+
+/**
+ * Runs 'step', measuring its wall time and the peak of bytes it had allocated at once on top of what was
+ * already allocated before it started. The peak is always zero when the CountingAllocator is not installed.
+*/
+pub fn measure<T>(step: impl FnOnce() -> T) -> (T, Measurement) {
+  let baseline = ALLOCATED_BYTES.load(Ordering::Relaxed);
+  PEAK_BYTES.store(baseline, Ordering::Relaxed);
+
+  let start = Instant::now();
+  let value = step();
+  let elapsed = start.elapsed();
+
+  let peak_bytes = PEAK_BYTES.load(Ordering::Relaxed).saturating_sub(baseline);
+  (value, Measurement { elapsed, peak_bytes })
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    let pointer = System.alloc(layout);
+    if !pointer.is_null() { record_allocation(layout.size()) }
+    pointer
+  }
+
+  unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+    let pointer = System.alloc_zeroed(layout);
+    if !pointer.is_null() { record_allocation(layout.size()) }
+    pointer
+  }
+
+  unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+    System.dealloc(pointer, layout);
+    record_deallocation(layout.size());
+  }
+
+  unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    let new_pointer = System.realloc(pointer, layout, new_size);
+    if !new_pointer.is_null() {
+      match new_size > layout.size() {
+        true => record_allocation(new_size - layout.size()),
+        false => record_deallocation(layout.size() - new_size),
+      }
+    }
+    new_pointer
+  }
+}
@@ -0,0 +1,195 @@
+use std::time::Duration;
+
+use aoc_common::solver::Answer;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::days::{Day, DayRun};
+use crate::measure::Measurement;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+#[derive(Serialize)]
+struct Timing {
+  elapsed_ns: u64,
+  peak_bytes: usize
+}
+
+/// One JSON record per solved part, repeating the timing of the parse step it shares with the other part
+#[derive(Serialize)]
+struct Record {
+  day: Day,
+  part: u8,
+  answer: Value,
+  answer_type: &'static str,
+  parse: Timing,
+  solve: Timing
+}
+
+// ==================================================== CONSTANTS ====================================================
+const BYTE_UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+const HEADER: [&str; 5] = ["Day", "Step", "Answer", "Time", "Peak memory"];
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+pub fn format_bytes(bytes: usize) -> String {
+  let mut value = bytes as f64;
+  let mut unit: usize = 0;
+  while value >= 1024.0 && unit < BYTE_UNITS.len() - 1 {
+    value /= 1024.0;
+    unit += 1;
+  }
+
+  match unit {
+    0 => format!("{} {}", bytes, BYTE_UNITS[0]),
+    _ => format!("{:.2} {}", value, BYTE_UNITS[unit]),
+  }
+}
+
+fn answer_value(answer: &Answer) -> (Value, &'static str) {
+  match answer {
+    Answer::Unsigned(value) => (Value::from(*value), "unsigned"),
+    Answer::Signed(value) => (Value::from(*value), "signed"),
+    Answer::Text(value) => (Value::from(value.as_str()), "text"),
+    Answer::Unsolved(reason) => (Value::from(reason.as_str()), "none"),
+  }
+}
+
+fn format_duration(duration: Duration) -> String {
+  format!("{:.2?}", duration)
+}
+
+/// One row for the parse step and one per solved part of every day, closed by a row with the totals
+fn table_rows(runs: &[DayRun]) -> Vec<[String; 5]> {
+  let mut rows: Vec<[String; 5]> = Vec::new();
+  for run in runs {
+    let day = format!("{:02}", run.day);
+    rows.push([day.clone(), "parse".to_owned(), String::new(), format_duration(run.parse.elapsed), format_bytes(run.parse.peak_bytes)]);
+    for part in run.parts.iter() {
+      let step = format!("part {}", part.part);
+      rows.push([day.clone(), step, part.answer.to_string(), format_duration(part.measurement.elapsed), format_bytes(part.measurement.peak_bytes)]);
+    }
+  }
+
+  let measurements = runs.iter()
+    .flat_map(|run| std::iter::once(run.parse).chain(run.parts.iter().map(|part| part.measurement)));
+  let (total_time, max_peak) = measurements.fold((Duration::ZERO, 0), |(time, peak), measurement|
+    (time + measurement.elapsed, usize::max(peak, measurement.peak_bytes)));
+  rows.push(["Total".to_owned(), String::new(), String::new(), format_duration(total_time), format_bytes(max_peak)]);
+
+  rows
+}
+
+/// Renders every run in a table aligned across all days, with the time and memory columns right-aligned
+pub fn table(runs: &[DayRun]) -> String {
+  let header: [String; 5] = HEADER.map(str::to_owned);
+  let rows = table_rows(runs);
+
+  let mut widths = [0; 5];
+  for row in std::iter::once(&header).chain(rows.iter()) {
+    for (width, cell) in widths.iter_mut().zip(row.iter()) {
+      *width = usize::max(*width, cell.chars().count());
+    }
+  }
+
+  let render = |row: &[String; 5]| {
+    let line = format!("{:<w0$}  {:<w1$}  {:<w2$}  {:>w3$}  {:>w4$}", row[0], row[1], row[2], row[3], row[4],
+      w0 = widths[0], w1 = widths[1], w2 = widths[2], w3 = widths[3], w4 = widths[4]);
+    line.trim_end().to_owned()
+  };
+  let separator = "-".repeat(widths.iter().sum::<usize>() + 2 * (widths.len() - 1));
+
+  let mut lines: Vec<String> = vec![render(&header), separator.clone()];
+  lines.extend(rows[..rows.len() - 1].iter().map(render));
+  lines.push(separator);
+  lines.push(render(&rows[rows.len() - 1]));
+  lines.join("\n")
+}
+
+
+/// Renders every solved part as an array of JSON records, keeping numeric answers as JSON numbers
+pub fn json(runs: &[DayRun]) -> String {
+  let records: Vec<Record> = runs.iter()
+    .flat_map(|run| run.parts.iter().map(move |part| {
+      let (answer, answer_type) = answer_value(&part.answer);
+      Record {
+        day: run.day,
+        part: part.part.number(),
+        answer,
+        answer_type,
+        parse: Timing::from(run.parse),
+        solve: Timing::from(part.measurement),
+      }
+    }))
+    .collect();
+
+  serde_json::to_string_pretty(&records).expect("🚨 Records are always serializable")
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl From<Measurement> for Timing {
+  fn from(measurement: Measurement) -> Self {
+    let elapsed_ns = u64::try_from(measurement.elapsed.as_nanos()).unwrap_or(u64::MAX);
+    Timing { elapsed_ns, peak_bytes: measurement.peak_bytes }
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::days::PartRun;
+  use crate::measure::Measurement;
+  use aoc_common::solver::Part;
+
+  fn measurement(micros: u64, peak_bytes: usize) -> Measurement {
+    Measurement { elapsed: Duration::from_micros(micros), peak_bytes }
+  }
+
+  #[test]
+  fn bytes_use_binary_units() {
+    assert_eq!(format_bytes(0), "0 B");
+    assert_eq!(format_bytes(1023), "1023 B");
+    assert_eq!(format_bytes(1536), "1.50 KiB");
+    assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MiB");
+  }
+
+  fn sample_runs() -> Vec<DayRun> {
+    vec![DayRun {
+      day: 7,
+      parse: measurement(250, 2048),
+      parts: vec![
+        PartRun { part: Part::One, answer: 6440_u32.into(), measurement: measurement(1000, 100) },
+        PartRun { part: Part::Two, answer: 5905_u32.into(), measurement: measurement(1500, 4096) },
+      ]
+    }]
+  }
+
+  #[test]
+  fn table_is_aligned_and_totalled() {
+    let table = table(&sample_runs());
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 7);
+    assert!(lines[3].starts_with("07     part 1  6440"));
+    assert!(lines[6].starts_with("Total"));
+    assert!(lines[6].ends_with("2.75ms     4.00 KiB"));
+
+    assert_eq!(lines[2], "07     parse           250.00µs     2.00 KiB");
+    assert_eq!(lines[4], "07     part 2  5905      1.50ms     4.00 KiB");
+  }
+
+  #[test]
+  fn json_records_per_part() {
+    let mut runs = sample_runs();
+    runs[0].parts[1].answer = "CHARLIE".into();
+
+    let records: Value = serde_json::from_str(&json(&runs)).unwrap();
+    assert_eq!(records.as_array().unwrap().len(), 2);
+    assert_eq!(records[0]["day"], 7);
+    assert_eq!(records[0]["part"], 1);
+    assert_eq!(records[0]["answer"], 6440);
+    assert_eq!(records[0]["answer_type"], "unsigned");
+    assert_eq!(records[0]["parse"]["elapsed_ns"], 250_000);
+    assert_eq!(records[1]["answer"], "CHARLIE");
+    assert_eq!(records[1]["answer_type"], "text");
+    assert_eq!(records[1]["solve"]["peak_bytes"], 4096);
+  }
+}
@@ -0,0 +1,250 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::days::Day;
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+#[derive(Debug)]
+pub enum ScaffoldError {
+  Exists(PathBuf),
+  Io {
+    file: PathBuf,
+    source: io::Error
+  },
+  /// A file the crate is registered in does not look the way it is expected to
+  Layout {
+    file: PathBuf,
+    reason: String
+  },
+}
+
+/// Adds the new day to the text of a file it is registered in
+type Registration = fn(&str, Day) -> Result<String, ScaffoldError>;
+
+/// Template file and where it goes inside the new 'day-NN' directory
+struct Template {
+  path: &'static str,
+  content: &'static str
+}
+
+// ==================================================== CONSTANTS ====================================================
+const PLACEHOLDER_DAY: &str = "{{DAY}}";
+const PLACEHOLDER_EXAMPLE: &str = "{{EXAMPLE}}";
+const TEMPLATES: [Template; 3] = [
+  Template { path: "Cargo.toml", content: include_str!("../templates/day/Cargo.toml.template") },
+  Template { path: "src/lib.rs", content: include_str!("../templates/day/lib.rs.template") },
+  Template { path: "src/main.rs", content: include_str!("../templates/day/main.rs.template") },
+];
+const WORKSPACE_MANIFEST: &str = "Cargo.toml";
+const RUNNER_MANIFEST: &str = "aoc/Cargo.toml";
+const RUNNER_DAYS: &str = "aoc/src/days.rs";
+const SOLUTIONS_DECLARATION: &str = "const SOLUTIONS: [(Day, Solution); ";
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+/// Fills the template for the given day, the example becoming the body of a string literal. Trailing line breaks
+/// are dropped so the literal ends on the last example line, as in the days written by hand
+pub fn render(template: &str, day: Day, example: &str) -> String {
+  let example = example.trim_end_matches(['\r', '\n']).replace('\\', "\\\\").replace('"', "\\\"");
+  template.replace(PLACEHOLDER_DAY, &format!("{:02}", day)).replace(PLACEHOLDER_EXAMPLE, &example)
+}
+
+/// Inserts 'line' among the lines 'entry_day' recognises as registered days, keeping them sorted by day
+fn insert_sorted<F>(text: &str, file: &str, day: Day, line: String, entry_day: F) -> Result<String, ScaffoldError>
+  where F: Fn(&str) -> Option<Day> {
+
+  let layout = |reason: String| ScaffoldError::Layout { file: PathBuf::from(file), reason };
+  let mut lines: Vec<&str> = text.lines().collect();
+  let entries: Vec<(usize, Day)> = lines.iter().enumerate()
+    .filter_map(|(index, line)| entry_day(line).map(|entry| (index, entry)))
+    .collect();
+
+  if entries.iter().any(|&(_, entry)| entry == day) {
+    return Err(layout(format!("day '{}' is already registered", day)));
+  }
+  let &(last_index, _) = entries.last().ok_or_else(|| layout("no registered day to insert after".to_owned()))?;
+  let position = entries.iter()
+    .find(|&&(_, entry)| entry > day)
+    .map_or(last_index + 1, |&(index, _)| index);
+
+  lines.insert(position, &line);
+  Ok(lines.join("\n") + if text.ends_with('\n') { "\n" } else { "" })
+}
+
+/// Adds the crate to the workspace 'members' list
+pub fn register_member(manifest: &str, day: Day) -> Result<String, ScaffoldError> {
+  insert_sorted(manifest, WORKSPACE_MANIFEST, day, format!("  \"day-{:02}\",", day), |line| {
+    line.trim().strip_prefix("\"day-")?.strip_suffix("\",")?.parse().ok()
+  })
+}
+
+/// Adds the crate as a path dependency of the runner
+pub fn register_dependency(manifest: &str, day: Day) -> Result<String, ScaffoldError> {
+  let line = format!("day-{:02} = {{ path = \"../day-{:02}\" }}", day, day);
+  insert_sorted(manifest, RUNNER_MANIFEST, day, line, |line| {
+    line.strip_prefix("day-")?.split_once(" = { path")?.0.parse().ok()
+  })
+}
+
+/// Adds the day to the runner 'SOLUTIONS' table, growing its declared length by one
+pub fn register_solution(days: &str, day: Day) -> Result<String, ScaffoldError> {
+  let line = format!("  ({}, solution::<day_{:02}::Puzzle>()),", day, day);
+  let registered = insert_sorted(days, RUNNER_DAYS, day, line, |line| {
+    line.trim().strip_prefix('(')?.split_once(", solution::<")?.0.parse().ok()
+  })?;
+
+  let layout = || ScaffoldError::Layout { file: PathBuf::from(RUNNER_DAYS), reason: "no 'SOLUTIONS' table".to_owned() };
+  let start = registered.find(SOLUTIONS_DECLARATION).ok_or_else(layout)? + SOLUTIONS_DECLARATION.len();
+  let length = registered[start..].find(']').ok_or_else(layout)?;
+  let count: usize = registered[start..start + length].parse().map_err(|_| layout())?;
+  Ok(format!("{}{}{}", &registered[..start], count + 1, &registered[start + length..]))
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+  fs::read_to_string(path).map_err(|source| ScaffoldError::Io { file: path.to_owned(), source })
+}
+
+fn write(path: &Path, content: &str) -> Result<(), ScaffoldError> {
+  if let Some(parent) = path.parent() {
+    fs::create_dir_all(parent).map_err(|source| ScaffoldError::Io { file: parent.to_owned(), source })?;
+  }
+  fs::write(path, content).map_err(|source| ScaffoldError::Io { file: path.to_owned(), source })
+}
+
+/// Generates 'day-NN' under the workspace 'root' and registers it in the workspace and the runner. Every
+/// registration is computed before anything is written, so a malformed file leaves the workspace untouched.
+/// Returns the paths written
+pub fn new_day(root: &Path, day: Day, example: &str) -> Result<Vec<PathBuf>, ScaffoldError> {
+  let directory = root.join(format!("day-{:02}", day));
+  if directory.exists() {
+    return Err(ScaffoldError::Exists(directory));
+  }
+
+  let registrations: [(&str, Registration); 3] = [
+    (WORKSPACE_MANIFEST, register_member),
+    (RUNNER_MANIFEST, register_dependency),
+    (RUNNER_DAYS, register_solution),
+  ];
+  let mut files: Vec<(PathBuf, String)> = registrations.iter()
+    .map(|&(file, register)| {
+      let path = root.join(file);
+      Ok((path.clone(), register(&read(&path)?, day)?))
+    })
+    .collect::<Result<_, ScaffoldError>>()?;
+  files.extend(TEMPLATES.iter().map(|template| (directory.join(template.path), render(template.content, day, example))));
+
+  for (path, content) in &files {
+    write(path, content)?;
+  }
+  Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl fmt::Display for ScaffoldError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ScaffoldError::Exists(directory) =>
+        write!(f, "🚨 Directory '{}' already exists", directory.display()),
+      ScaffoldError::Io { file, source } =>
+        write!(f, "🚨 Could not access '{}': {}", file.display(), source),
+      ScaffoldError::Layout { file, reason } =>
+        write!(f, "🚨 Could not register the day in '{}': {}", file.display(), reason),
+    }
+  }
+}
+
+impl std::error::Error for ScaffoldError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      ScaffoldError::Io { source, .. } => Some(source),
+      ScaffoldError::Exists(_) | ScaffoldError::Layout { .. } => None,
+    }
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const MANIFEST: &str = "\
+[workspace]
+members = [
+  \"aoc\",
+  \"day-01\",
+  \"day-03\",
+]
+";
+
+  const DAYS: &str = "\
+const SOLUTIONS: [(Day, Solution); 2] = [
+  (1, solution::<day_01::Puzzle>()),
+  (3, solution::<day_03::Puzzle>()),
+];
+";
+
+  #[test]
+  fn templates_keep_the_banners() {
+    let lib = render(TEMPLATES[1].content, 7, "");
+    for banner in ["TYPE AND STRUCT DEFINITIONS", "AUXILIARY FUNCTIONS", "IMPLEMENTATIONS", "TESTS"] {
+      assert!(lib.contains(banner), "missing banner '{}'", banner);
+    }
+    assert!(render(TEMPLATES[0].content, 7, "").contains("name = \"day-07\""));
+    assert!(render(TEMPLATES[2].content, 7, "").contains("use day_07::Puzzle;"));
+  }
+
+  #[test]
+  fn example_is_escaped() {
+    let lib = render(TEMPLATES[1].content, 7, "say \"hi\"\n\\o/\n\n");
+    assert!(lib.contains("const EXAMPLE: &str = \"\\\nsay \\\"hi\\\"\n\\\\o/\";"));
+  }
+
+  #[test]
+  fn members_stay_sorted() {
+    let manifest = register_member(MANIFEST, 2).unwrap();
+    assert!(manifest.contains("  \"day-01\",\n  \"day-02\",\n  \"day-03\",\n]"));
+    assert!(register_member(&manifest, 4).unwrap().contains("  \"day-03\",\n  \"day-04\",\n]"));
+    assert!(register_member(MANIFEST, 3).is_err());
+  }
+
+  #[test]
+  fn dependencies_stay_sorted() {
+    let manifest = "[dependencies]\naoc-common = { path = \"../aoc-common\" }\nday-01 = { path = \"../day-01\" }\nserde = \"1.0\"\n";
+    assert_eq!(
+      register_dependency(manifest, 12).unwrap(),
+      "[dependencies]\naoc-common = { path = \"../aoc-common\" }\nday-01 = { path = \"../day-01\" }\n\
+      day-12 = { path = \"../day-12\" }\nserde = \"1.0\"\n");
+  }
+
+  #[test]
+  fn solutions_grow_by_one() {
+    assert_eq!(register_solution(DAYS, 2).unwrap(), "\
+const SOLUTIONS: [(Day, Solution); 3] = [
+  (1, solution::<day_01::Puzzle>()),
+  (2, solution::<day_02::Puzzle>()),
+  (3, solution::<day_03::Puzzle>()),
+];
+");
+  }
+
+  #[test]
+  fn new_day_writes_and_registers() {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    write(&root.join(WORKSPACE_MANIFEST), MANIFEST).unwrap();
+    write(&root.join(RUNNER_MANIFEST), "[dependencies]\nday-01 = { path = \"../day-01\" }\n").unwrap();
+    write(&root.join(RUNNER_DAYS), DAYS).unwrap();
+
+    let written = new_day(&root, 2, "1\n2").unwrap();
+    assert_eq!(written.len(), 6);
+    assert!(read(&root.join("day-02/src/lib.rs")).unwrap().contains("= \"\\\n1\n2\";"));
+    assert!(read(&root.join(RUNNER_DAYS)).unwrap().contains("(2, solution::<day_02::Puzzle>())"));
+    assert!(matches!(new_day(&root, 2, ""), Err(ScaffoldError::Exists(_))));
+
+    // Nothing is written when a registration fails
+    assert!(matches!(new_day(&root, 3, ""), Err(ScaffoldError::Layout { .. })));
+    assert!(!root.join("day-03").exists());
+    fs::remove_dir_all(&root).unwrap();
+  }
+}
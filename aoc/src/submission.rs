@@ -0,0 +1,175 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+  Right,
+  Wrong,
+  TooHigh,
+  TooLow,
+  /// Answered too soon after the previous attempt, the answer was not checked
+  Wait,
+  /// The part was already solved, the answer was not checked
+  AlreadySolved,
+  /// The response could not be understood
+  Unknown,
+}
+
+/// What the website made of a submitted answer
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Submission {
+  pub verdict: Verdict,
+  /// Seconds to wait before the next answer is accepted, when the response says so
+  pub wait_seconds: Option<u64>,
+  /// Text of the response, without its markup
+  pub message: String
+}
+
+// ==================================================== CONSTANTS ====================================================
+const RIGHT: &str = "That's the right answer";
+const WRONG: &str = "That's not the right answer";
+const TOO_HIGH: &str = "your answer is too high";
+const TOO_LOW: &str = "your answer is too low";
+const TOO_RECENT: &str = "You gave an answer too recently";
+const WRONG_LEVEL: &str = "You don't seem to be solving the right level";
+const SECONDS_PER_MINUTE: u64 = 60;
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+/// Text of the '<article>' holding the response, or of the whole page when there is none, with tags removed and
+/// whitespace collapsed
+fn article_text(html: &str) -> String {
+  let article = html.find("<article")
+    .and_then(|start| html[start..].find("</article>").map(|end| &html[start..start + end]))
+    .unwrap_or(html);
+
+  let mut text = String::with_capacity(article.len());
+  let mut in_tag = false;
+  for character in article.chars() {
+    match character {
+      '<' => in_tag = true,
+      '>' => in_tag = false,
+      _ if !in_tag => text.push(character),
+      _ => {},
+    }
+  }
+
+  text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Reads 'You have 1m 23s left to wait' and 'please wait 5 minutes before trying again', in seconds
+fn parse_wait(message: &str) -> Option<u64> {
+  if let Some(end) = message.find(" left to wait") {
+    let start = message[..end].rfind("You have ")? + "You have ".len();
+    return message[start..end].split_whitespace()
+      .map(|amount| match (amount.strip_suffix('m'), amount.strip_suffix('s')) {
+        (Some(minutes), _) => minutes.parse::<u64>().ok().map(|minutes| minutes * SECONDS_PER_MINUTE),
+        (_, Some(seconds)) => seconds.parse::<u64>().ok(),
+        _ => None,
+      })
+      .sum();
+  }
+
+  let lowercase = message.to_lowercase();
+  let start = lowercase.find("please wait ")? + "please wait ".len();
+  let mut words = lowercase[start..].split_whitespace();
+  let minutes = match words.next()? {
+    "one" => 1,
+    amount => amount.parse().ok()?,
+  };
+  words.next()?.starts_with("minute").then_some(minutes * SECONDS_PER_MINUTE)
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Submission {
+  pub fn parse(html: &str) -> Submission {
+    let message = article_text(html);
+    let verdict = if message.contains(RIGHT) {
+      Verdict::Right
+    } else if message.contains(WRONG) && message.contains(TOO_HIGH) {
+      Verdict::TooHigh
+    } else if message.contains(WRONG) && message.contains(TOO_LOW) {
+      Verdict::TooLow
+    } else if message.contains(WRONG) {
+      Verdict::Wrong
+    } else if message.contains(TOO_RECENT) {
+      Verdict::Wait
+    } else if message.contains(WRONG_LEVEL) {
+      Verdict::AlreadySolved
+    } else {
+      Verdict::Unknown
+    };
+
+    let wait_seconds = match verdict {
+      Verdict::Right | Verdict::AlreadySolved => None,
+      _ => parse_wait(&message),
+    };
+    Submission { verdict, wait_seconds, message }
+  }
+}
+
+impl Verdict {
+  /// Whether the website judged the answer itself
+  pub fn is_judged(&self) -> bool {
+    matches!(self, Verdict::Right | Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+  }
+}
+
+impl fmt::Display for Verdict {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let text = match self {
+      Verdict::Right => "right",
+      Verdict::Wrong => "wrong",
+      Verdict::TooHigh => "too high",
+      Verdict::TooLow => "too low",
+      Verdict::Wait => "sent too soon",
+      Verdict::AlreadySolved => "already solved",
+      Verdict::Unknown => "not understood",
+    };
+    write!(f, "{}", text)
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn page(article: &str) -> String {
+    format!("<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", article)
+  }
+
+  #[test]
+  fn judged_answers() {
+    let right = Submission::parse(&page("That's the right answer!  You are <em>one gold star</em> closer."));
+    assert_eq!((right.verdict, right.wait_seconds), (Verdict::Right, None));
+    assert!(right.message.starts_with("That's the right answer! You are one gold star closer."));
+
+    let high = Submission::parse(&page("That's not the right answer; your answer is too high.  If you're stuck, \
+      make sure you're using the full input data.  Please wait one minute before trying again. \
+      <a href=\"/2023/day/5\">[Return to Day 5]</a>"));
+    assert_eq!((high.verdict, high.wait_seconds), (Verdict::TooHigh, Some(60)));
+
+    let low = Submission::parse(&page("That's not the right answer; your answer is too low.  \
+      Please wait 5 minutes before trying again."));
+    assert_eq!((low.verdict, low.wait_seconds), (Verdict::TooLow, Some(300)));
+
+    let wrong = Submission::parse(&page("That's not the right answer.  If you're stuck, ask for help."));
+    assert_eq!((wrong.verdict, wrong.wait_seconds), (Verdict::Wrong, None));
+  }
+
+  #[test]
+  fn unjudged_answers() {
+    let wait = Submission::parse(&page("You gave an answer too recently; you have to wait after submitting an \
+      answer before trying again.  You have 1m 23s left to wait."));
+    assert_eq!((wait.verdict, wait.wait_seconds), (Verdict::Wait, Some(83)));
+    let wait = Submission::parse(&page("You gave an answer too recently. You have 38s left to wait."));
+    assert_eq!(wait.wait_seconds, Some(38));
+
+    let solved = Submission::parse(&page("You don't seem to be solving the right level. Did you already complete it?"));
+    assert_eq!(solved.verdict, Verdict::AlreadySolved);
+    assert_eq!(Submission::parse("<html>Server error</html>").verdict, Verdict::Unknown);
+  }
+}
@@ -0,0 +1,37 @@
+//! Checks every day against the answers pinned in 'day-NN/answers.txt' (part one on the first line,
+//! part two on the second). Days without a local 'input.txt' or 'answers.txt' are skipped.
+
+use std::fs;
+use std::path::PathBuf;
+
+use aoc::days;
+use aoc::days::Day;
+use aoc_common::solver::Part;
+
+fn day_directory(day: Day) -> PathBuf {
+  PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join(format!("day-{:02}", day))
+}
+
+#[test]
+fn pinned_answers() {
+  let mut checked_days: usize = 0;
+
+  for day in days::available_days() {
+    let directory = day_directory(day);
+    let input = directory.join("input.txt");
+    let Ok(answers) = fs::read_to_string(directory.join("answers.txt")) else { continue };
+    if !input.exists() { continue }
+
+    let expected: Vec<&str> = answers.lines().map(str::trim).collect();
+    for (part, expected) in [Part::One, Part::Two].into_iter().zip(expected) {
+      let answer = days::solve(day, part, input.to_string_lossy().into_owned())
+        .expect("🚨 Day listed as available has no solution")
+        .unwrap_or_else(|error| panic!("{}", error));
+      assert_eq!(answer.to_string(), expected, "🚨 Wrong answer for day {:02} part {}", day, part);
+    }
+
+    checked_days += 1;
+  }
+
+  println!("🎄 Checked the pinned answers of {} days", checked_days);
+}
@@ -0,0 +1,97 @@
+//! Checks every day against the examples 'aoc examples' extracted from its puzzle page into 'day-NN/examples'
+//! (the input of each part in 'part-N.txt', its answer on line N of 'answers.txt'). Parts without an input or
+//! an answer are skipped, but every day must check at least one part unless it is exempted below.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc::days;
+use aoc::days::Day;
+use aoc::examples;
+use aoc_common::solver::Part;
+
+/// Days whose page examples cannot be checked as fixtures, with the reason why
+const DAYS_WITHOUT_FIXTURES: [(Day, &str); 1] = [
+  (21, "its examples walk from 6 to 5000 steps, while the solver walks the 64 and 26501365 of the puzzle"),
+];
+
+/// Runs the fixtures of the day found in the directory, returning how many parts were checked
+fn check_fixtures(day: Day, directory: &Path) -> usize {
+  let Ok(answers) = fs::read_to_string(examples::fixture_answers(directory)) else { return 0 };
+  let mut checked_parts: usize = 0;
+
+  for (part, expected) in [Part::One, Part::Two].into_iter().zip(answers.lines().map(str::trim)) {
+    let input = examples::fixture_input(directory, part.number());
+    if expected.is_empty() || !input.exists() { continue }
+
+    let answer = days::solve(day, part, input.to_string_lossy().into_owned())
+      .expect("🚨 Day listed as available has no solution")
+      .unwrap_or_else(|error| panic!("{}", error));
+    assert_eq!(answer.to_string(), expected, "🚨 Wrong example answer for day {:02} part {}", day, part);
+    checked_parts += 1;
+  }
+
+  checked_parts
+}
+
+#[test]
+fn extracted_examples() {
+  let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+  let mut checked_parts: usize = 0;
+  for day in days::available_days() {
+    if let Some((_, reason)) = DAYS_WITHOUT_FIXTURES.iter().find(|&&(exempt_day, _)| exempt_day == day) {
+      println!("⏭️ Skipped day {:02}: {}", day, reason);
+      continue;
+    }
+    let day_checked_parts = check_fixtures(day, &examples::fixtures_dir(&root, day));
+    assert!(day_checked_parts > 0, "🚨 No extracted example was found to check for day {:02}", day);
+    checked_parts += day_checked_parts;
+  }
+
+  assert!(checked_parts > 0, "🚨 No extracted example was found to check");
+  println!("🎄 Checked {} extracted examples", checked_parts);
+}
+
+#[test]
+fn saved_page_to_fixtures() {
+  let page = "\
+<html><body><main>
+<article class=\"day-desc\"><h2>--- Day 1 ---</h2>
+<p>Each line hides a value, for example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>Adding them up gives <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54632</code>.</p>
+<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>
+<p>Spelled out digits count too. Take this example:</p>
+<pre><code>two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+</code></pre>
+<p>The values are <code>29</code>, <code>83</code>, <code>13</code>, <code>24</code>, <code>42</code>, <code>14</code>, and
+<code>76</code>, adding up to <code><em>281</em></code>.</p>
+</article>
+</main></body></html>
+";
+
+  let directory = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+  let _ = fs::remove_dir_all(&directory);
+  fs::create_dir_all(&directory).unwrap();
+  let saved_page = directory.join("page.html");
+  fs::write(&saved_page, page).unwrap();
+
+  let fixtures = directory.join("examples");
+  assert_eq!(examples::write_fixtures(&saved_page, &fixtures).unwrap().len(), 3);
+  assert_eq!(fs::read_to_string(examples::fixture_answers(&fixtures)).unwrap(), "142\n281\n");
+  assert_eq!(check_fixtures(1, &fixtures), 2);
+
+  fs::remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,100 @@
+//! Downloads inputs from a local stand-in of the Advent Of Code website, checking the session cookie is sent
+//! and that cached inputs are not downloaded twice.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use aoc::client::{Client, ClientError};
+use aoc::config::Config;
+use aoc::days;
+use tiny_http::{Response, Server};
+
+const SESSION: &str = "53616c7465645f5f";
+const INPUT: &str = "467..114..\n...*......\n";
+
+/// Serves the day 3 input to the expected session until the test process exits, counting the requests
+fn serve() -> (String, Arc<AtomicUsize>) {
+  let server = Server::http("127.0.0.1:0").unwrap();
+  let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+  let requests = Arc::new(AtomicUsize::new(0));
+
+  let counter = Arc::clone(&requests);
+  thread::spawn(move || {
+    for request in server.incoming_requests() {
+      counter.fetch_add(1, Ordering::SeqCst);
+      let authenticated = request.headers().iter()
+        .any(|header| header.field.equiv("Cookie") && header.value.as_str() == format!("session={}", SESSION));
+      let response = match (authenticated, request.url()) {
+        (false, _) => Response::from_string("Puzzle inputs differ by user.  Please log in to get your puzzle input.")
+          .with_status_code(400),
+        (true, "/2023/day/3/input") => Response::from_string(INPUT),
+        (true, _) => Response::from_string("404 Not Found").with_status_code(404),
+      };
+      request.respond(response).unwrap();
+    }
+  });
+
+  (base_url, requests)
+}
+
+fn config(name: &str, session: Option<&str>, base_url: &str) -> Config {
+  let cache_dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+  let _ = fs::remove_dir_all(&cache_dir);
+  Config { session: session.map(str::to_owned), base_url: base_url.to_owned(), cache_dir }
+}
+
+#[test]
+fn inputs_are_cached() {
+  let (base_url, requests) = serve();
+  let client = Client::new(config("cached", Some(SESSION), &base_url));
+
+  let fetched = client.fetch_input(3, false).unwrap();
+  assert!(fetched.downloaded);
+  assert_eq!(Some(&fetched.path), client.config().cached_input(3).as_ref());
+  assert_eq!(fs::read_to_string(&fetched.path).unwrap(), INPUT);
+
+  assert!(!client.fetch_input(3, false).unwrap().downloaded);
+  assert_eq!(requests.load(Ordering::SeqCst), 1);
+  assert!(client.fetch_input(3, true).unwrap().downloaded);
+  assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+  fs::remove_dir_all(&client.config().cache_dir).unwrap();
+}
+
+#[test]
+fn runner_finds_cached_inputs() {
+  let (base_url, _) = serve();
+  let client = Client::new(config("runner", Some(SESSION), &base_url));
+  let fetched = client.fetch_input(3, false).unwrap();
+
+  // The cached input is preferred, and days that were never fetched fall back to 'day-NN/input.txt'
+  assert_eq!(PathBuf::from(days::default_input(3, Some(client.config()))), fetched.path);
+  assert_eq!(days::default_input(4, Some(client.config())), "day-04/input.txt");
+  assert_eq!(days::default_input(3, None), "day-03/input.txt");
+
+  // Another user does not see the input fetched by the first one
+  let other = Client::new(Config { session: Some("6f746865725f7573".to_owned()), ..client.config().clone() });
+  assert_eq!(days::default_input(3, Some(other.config())), "day-03/input.txt");
+
+  fs::remove_dir_all(&client.config().cache_dir).unwrap();
+}
+
+#[test]
+fn failures_are_reported() {
+  let (base_url, requests) = serve();
+
+  let missing = Client::new(config("missing", None, &base_url));
+  assert!(matches!(missing.fetch_input(3, false), Err(ClientError::MissingSession)));
+  assert_eq!(requests.load(Ordering::SeqCst), 0);
+
+  let expired = Client::new(config("expired", Some("expired"), &base_url));
+  assert!(matches!(expired.fetch_input(3, false), Err(ClientError::Status { status: 400, .. })));
+  assert!(!expired.config().cached_input(3).unwrap().exists());
+
+  let locked = Client::new(config("locked", Some(SESSION), &base_url));
+  let error = locked.fetch_input(25, false).unwrap_err();
+  assert!(error.to_string().contains("not be unlocked yet"), "{}", error);
+}
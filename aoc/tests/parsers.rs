@@ -0,0 +1,35 @@
+//! Feeds every day's parser with inputs generated by 'aoc::inputs': valid inputs must parse, render back to
+//! the same text and be solved without panicking, and their corrupted variants must be rejected with an error
+//! rather than a panic.
+
+use std::panic;
+
+use aoc::days;
+use aoc::inputs;
+use aoc_common::random::Random;
+use proptest::prelude::*;
+
+proptest! {
+  #[test]
+  fn valid_inputs_round_trip(day in 1..=25u8, seed in any::<u64>()) {
+    let text = inputs::valid(day, &mut Random::new(seed)).expect("🚨 Day has no input generator");
+    match days::round_trip(day, &text).expect("🚨 Day has no solution") {
+      Ok(rendered) => prop_assert_eq!(rendered, text, "🚨 Day {:02} did not render its input back", day),
+      Err(error) => prop_assert!(false, "🚨 Day {:02} rejected a valid input: {}\n{}", day, error, text),
+    }
+  }
+
+  #[test]
+  fn valid_inputs_are_solved(day in 1..=25u8, seed in any::<u64>()) {
+    let text = inputs::valid(day, &mut Random::new(seed)).expect("🚨 Day has no input generator");
+    let solved = panic::catch_unwind(|| days::solve_text(day, &text).expect("🚨 Day has no solution"));
+    prop_assert!(matches!(solved, Ok(Ok(_))), "🚨 Day {:02} could not solve a valid input:\n{}", day, text);
+  }
+
+  #[test]
+  fn corrupted_inputs_are_rejected(day in 1..=25u8, seed in any::<u64>()) {
+    let text = inputs::corrupted(day, &mut Random::new(seed)).expect("🚨 Day has no input generator");
+    let result = days::round_trip(day, &text).expect("🚨 Day has no solution");
+    prop_assert!(result.is_err(), "🚨 Day {:02} accepted a corrupted input:\n{}", day, text);
+  }
+}
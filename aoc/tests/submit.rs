@@ -0,0 +1,86 @@
+//! Submits answers to a local stand-in of the Advent Of Code website, checking the responses are understood
+//! and that the ledger keeps answers known to be wrong from being sent again.
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use aoc::client::{Client, SubmitOutcome};
+use aoc::config::Config;
+use aoc::ledger::{Ledger, Refusal};
+use aoc::submission::Verdict;
+use aoc_common::solver::Part;
+use tiny_http::{Method, Response, Server};
+
+const SESSION: &str = "53616c7465645f5f";
+const RIGHT_ANSWER: &str = "142";
+
+fn article(text: &str) -> String {
+  format!("<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", text)
+}
+
+/// Judges answers to day 1 part 1 until the test process exits, keeping the form of every request
+fn serve() -> (String, Arc<Mutex<Vec<String>>>) {
+  let server = Server::http("127.0.0.1:0").unwrap();
+  let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+  let forms = Arc::new(Mutex::new(Vec::new()));
+
+  let received = Arc::clone(&forms);
+  thread::spawn(move || {
+    for mut request in server.incoming_requests() {
+      let mut form = String::new();
+      request.as_reader().read_to_string(&mut form).unwrap();
+      received.lock().unwrap().push(form.clone());
+
+      let text = match (request.method(), request.url(), form.strip_prefix("level=1&answer=")) {
+        (Method::Post, "/2023/day/1/answer", Some(RIGHT_ANSWER)) =>
+          "That's the right answer!  You are <em>one gold star</em> closer to restoring snow operations.",
+        (Method::Post, "/2023/day/1/answer", Some(answer)) if answer.parse::<u64>().unwrap() > 142 =>
+          "That's not the right answer; your answer is too high.  Please wait one minute before trying again.",
+        (Method::Post, "/2023/day/1/answer", Some(_)) =>
+          "That's not the right answer; your answer is too low.  Please wait one minute before trying again.",
+        _ => "You don't seem to be solving the right level.  Did you already complete it?",
+      };
+      request.respond(Response::from_string(article(text))).unwrap();
+    }
+  });
+
+  (base_url, forms)
+}
+
+#[test]
+fn ledger_guards_submissions() {
+  let (base_url, forms) = serve();
+  let cache_dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+  let client = Client::new(Config { session: Some(SESSION.to_owned()), base_url, cache_dir });
+  let mut ledger = Ledger::default();
+  let mut submit = |answer: &str, now: u64| client.submit_answer(&mut ledger, 1, Part::One, answer, now).unwrap();
+  let verdict = |outcome: SubmitOutcome| match outcome {
+    SubmitOutcome::Sent(submission) => Ok((submission.verdict, submission.wait_seconds)),
+    SubmitOutcome::Refused(refusal) => Err(refusal),
+  };
+
+  assert_eq!(verdict(submit("500", 1000)), Ok((Verdict::TooHigh, Some(60))));
+  assert_eq!(verdict(submit("600", 1010)), Err(Refusal::AboveBound(500)));
+  assert_eq!(verdict(submit("500", 1010)), Err(Refusal::KnownWrong(Verdict::TooHigh)));
+  assert_eq!(verdict(submit("7", 1010)), Err(Refusal::Wait(50)));
+  assert_eq!(verdict(submit("7", 1060)), Ok((Verdict::TooLow, Some(60))));
+  assert_eq!(verdict(submit("5", 1200)), Err(Refusal::BelowBound(7)));
+  assert_eq!(verdict(submit(RIGHT_ANSWER, 1200)), Ok((Verdict::Right, None)));
+  assert_eq!(verdict(submit("143", 1300)), Err(Refusal::AlreadySolved(RIGHT_ANSWER.to_owned())));
+
+  assert_eq!(*forms.lock().unwrap(), vec!["level=1&answer=500", "level=1&answer=7", "level=1&answer=142"]);
+  assert_eq!(ledger.attempts(1, Part::One).count(), 3);
+}
+
+#[test]
+fn unexpected_responses_are_recorded() {
+  let (base_url, _) = serve();
+  let cache_dir = std::env::temp_dir().join(format!("aoc-submit-level-{}", std::process::id()));
+  let client = Client::new(Config { session: Some(SESSION.to_owned()), base_url, cache_dir });
+  let mut ledger = Ledger::default();
+
+  let outcome = client.submit_answer(&mut ledger, 2, Part::Two, "9", 1000).unwrap();
+  assert!(matches!(outcome, SubmitOutcome::Sent(submission) if submission.verdict == Verdict::AlreadySolved));
+  assert_eq!(ledger.attempts(2, Part::Two).count(), 1);
+  assert_eq!(ledger.check(2, Part::Two, "9", 1000), Ok(()));
+}
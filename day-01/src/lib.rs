@@ -1,3 +1,6 @@
+use aoc_common::error::InputError;
+use aoc_common::solver::{Answer, Solver};
+
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 pub type CalibrationLine = Vec<char>;
 pub type CalibrationDigit = u32;
@@ -7,8 +10,10 @@ pub struct CalibrationValue {
   pub second_digit: CalibrationDigit,
 }
 
+pub struct Puzzle;
+
 // =============================================== AUXILIARY FUNCTIONS ===============================================
-fn find_pattern<'a>(string: &String, patterns: &'a Vec<(&str, &str)>) -> Option<(&'a str, &'a str, usize)> {
+fn find_pattern<'a>(string: &str, patterns: &'a [(&str, &str)]) -> Option<(&'a str, &'a str, usize)> {
   patterns.iter()
     .map(|&pattern| (pattern.0, pattern.1, string.find(pattern.0)))
     .filter(|pattern| pattern.2.is_some())
@@ -30,7 +35,16 @@ fn fix_calibration_values(line: CalibrationLine) -> CalibrationLine {
     string.replace_range(range_start..range_end, occurrence.1);
   }
 
-  string.chars().into_iter().collect()
+  string.chars().collect()
+}
+
+/// Lines only hold lowercase letters and digits
+fn parse_calibration_line(line_index: usize, line: &str) -> Result<CalibrationLine, InputError> {
+  if line.is_empty() { return Err(InputError::parse(line_index + 1, 1, line, "empty calibration line")) }
+  match line.chars().position(|char| !char.is_ascii_lowercase() && !char.is_ascii_digit()) {
+    Some(column_index) => Err(InputError::parse(line_index + 1, column_index + 1, line, "not a letter or a digit")),
+    None => Ok(line.chars().collect()),
+  }
 }
 
 pub fn retrieve_calibration_value(line: CalibrationLine, fix_written: bool) -> Option<CalibrationValue> {
@@ -47,6 +61,13 @@ pub fn retrieve_calibration_value(line: CalibrationLine, fix_written: bool) -> O
     })
 }
 
+pub fn sum_calibration_values(lines: &[CalibrationLine], fix_written: bool) -> CalibrationDigit {
+  lines.iter()
+    .filter_map(|line| retrieve_calibration_value(line.clone(), fix_written))
+    .map(|value| value.get_value())
+    .sum()
+}
+
 // ================================================= IMPLEMENTATIONS =================================================
 
 impl CalibrationValue {
@@ -59,3 +80,81 @@ impl CalibrationValue {
     self.first_digit * 10 + self.second_digit
   } 
 }
+
+impl Solver for Puzzle {
+  type Input = Vec<CalibrationLine>;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    text.lines().enumerate()
+      .map(|(line_index, line)| parse_calibration_line(line_index, line))
+      .collect()
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.iter()
+      .map(|line| line.iter().collect::<String>())
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    sum_calibration_values(input, false).into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    sum_calibration_values(input, true).into()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+  const EXAMPLE_WRITTEN: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+  #[test]
+  fn calibration_value_uses_first_and_last_digit() {
+    let value = retrieve_calibration_value("treb7uchet".chars().collect(), false).unwrap();
+    assert_eq!(value.get_value(), 77);
+    assert!(retrieve_calibration_value("abc".chars().collect(), false).is_none());
+  }
+
+  #[test]
+  fn calibration_value_with_overlapping_written_digits() {
+    let value = retrieve_calibration_value("eightwothree".chars().collect(), true).unwrap();
+    assert_eq!(value.get_value(), 83);
+  }
+
+  #[test]
+  fn malformed_lines_are_rejected() {
+    for text in ["treb7uchet\n\nabc1", "pqr3stu8vwx\n\n", "a1b2 c3d4"] {
+      assert!(Puzzle::parse(text).is_err(), "🚨 Input should not parse:\n{}", text);
+    }
+  }
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "142");
+  }
+
+  #[test]
+  fn example_part_two() {
+    let input = Puzzle::parse(EXAMPLE_WRITTEN).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "281");
+  }
+}
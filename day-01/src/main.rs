@@ -1,25 +1,23 @@
-// Load Local Modules
-mod read;
-
 // Imports
-use day_01::retrieve_calibration_value;
+use std::env;
 
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_01::Puzzle;
 
 fn main() {
 
-    let input = read::read_chars("input.txt".to_owned());
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let sum_calibration: u32 = input.clone().into_iter()
-        .map(|line| retrieve_calibration_value(line, false))
-        .filter_map(|value_option| value_option.map(|value| value.get_value()))
-        .sum();
+    let sum_calibration = Puzzle::part_one(&input);
     println!("\r📐 Sum of calibration values: '{}' (Part 1)", sum_calibration);
-    
+
     // Part 2
-    let sum_calibration_fixed: u32 = input.clone().into_iter()
-        .map(|line| retrieve_calibration_value(line, true))
-        .filter_map(|value_option| value_option.map(|value| value.get_value()))
-        .sum();
+    let sum_calibration_fixed = Puzzle::part_two(&input);
     println!("\r📐 Sum of calibration values parsed: '{}' (Part 2)", sum_calibration_fixed);
 }
\ No newline at end of file
@@ -1,10 +1,19 @@
+use aoc_common::error::InputError;
+use aoc_common::solver::{Answer, Solver};
+
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 pub type GameNumber = usize;
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Colour {
+  Red,
+  Green,
+  Blue
+}
+
+/// Cubes shown in a single draw, in the order they were listed
 pub struct Record {
-  count_red: usize,
-  count_green: usize,
-  count_blue: usize,
+  cubes: Vec<(usize, Colour)>,
 }
 
 pub struct Game {
@@ -12,50 +21,65 @@ pub struct Game {
   counts: Vec<Record>
 }
 
-// =============================================== AUXILIARY FUNCTIONS ===============================================
-fn parse_record(record_info: &str) -> Record {
-  let mut count_red: usize = 0;
-  let mut count_green: usize = 0;
-  let mut count_blue: usize = 0;
-
-  record_info.split(", ")
-    .into_iter()
-    .for_each(|count_info| {
-      let mut count_info_split: Vec<&str> = count_info.split(" ").collect();
-      let count_number: usize = count_info_split.remove(0).parse().unwrap(); 
-      
-      match count_info_split.remove(0) {
-        "red" => count_red = count_number,
-        "green" => count_green = count_number,
-        "blue" => count_blue = count_number,
-        _ => panic!("🚨 Unrecognized ball color")
-      }
-    });
+pub struct Puzzle;
 
-  Record { count_red, count_green, count_blue }
+// ==================================================== CONSTANTS ====================================================
+const COLOURS: [(Colour, &str); 3] = [(Colour::Red, "red"), (Colour::Green, "green"), (Colour::Blue, "blue")];
 
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+fn parse_record(line_index: usize, line: &str, record_info: &str) -> Result<Record, InputError> {
+  let error = |token: &str, reason: &str| InputError::at_token(line_index + 1, line, token, reason);
+  let mut cubes: Vec<(usize, Colour)> = Vec::new();
+
+  for count_info in record_info.split(", ") {
+    let (count_number, colour_name) = count_info.split_once(' ')
+      .ok_or_else(|| error(count_info, "cubes are not formatted as 'count colour'"))?;
+    let count_number: usize = count_number.parse().map_err(|_| error(count_number, "cube count is not a number"))?;
+    let colour = Colour::from(colour_name).ok_or_else(|| error(colour_name, "unrecognized cube colour"))?;
+    if cubes.iter().any(|&(_, seen)| seen == colour) {
+      return Err(error(colour_name, "colour shown twice in the same draw"));
+    }
+    cubes.push((count_number, colour));
+  }
+
+  Ok(Record { cubes })
 }
 
-pub fn parse_game(line: String) -> Game {
-  let mut split_header: Vec<&str> = line.split(": ").collect();
-  let game_number: GameNumber = split_header.remove(0)
-    .strip_prefix("Game ").unwrap()
-    .parse().unwrap();
+pub fn parse_game(line_index: usize, line: &str) -> Result<Game, InputError> {
+  let error = |token: &str, reason: &str| InputError::at_token(line_index + 1, line, token, reason);
+  let (header, records) = line.strip_prefix("Game ").and_then(|line| line.split_once(": "))
+    .ok_or_else(|| error(line, "game is not formatted as 'Game number: draws'"))?;
+  let game_number: GameNumber = header.parse().map_err(|_| error(header, "game number is not a number"))?;
 
-  let records: Vec<Record> = split_header.remove(0)
-    .split("; ")
-    .map(|record_info| parse_record(record_info))
-    .collect();
+  let records: Vec<Record> = records.split("; ")
+    .map(|record_info| parse_record(line_index, line, record_info))
+    .collect::<Result<_, _>>()?;
 
-  Game { game_number, counts: records }
+  Ok(Game { game_number, counts: records })
 }
 
 // ================================================= IMPLEMENTATIONS =================================================
+impl Colour {
+  fn from(name: &str) -> Option<Colour> {
+    COLOURS.iter().find(|&&(_, colour_name)| colour_name == name).map(|&(colour, _)| colour)
+  }
+
+  fn name(self) -> &'static str {
+    COLOURS.iter().find(|&&(colour, _)| colour == self).map(|&(_, name)| name).unwrap()
+  }
+}
+
 impl Record {
+  fn count(&self, colour: Colour) -> usize {
+    self.cubes.iter()
+      .find(|&&(_, shown)| shown == colour)
+      .map_or(0, |&(count, _)| count)
+  }
+
   fn check_possible_contain_only(&self, red_cubes: usize, green_cubes: usize, blue_cubes: usize) -> bool {
-    self.count_red <= red_cubes &&
-      self.count_green <= green_cubes &&
-      self.count_blue <= blue_cubes
+    self.count(Colour::Red) <= red_cubes &&
+      self.count(Colour::Green) <= green_cubes &&
+      self.count(Colour::Blue) <= blue_cubes
   }
 }
 
@@ -63,8 +87,7 @@ impl Game {
 
   pub fn check_possible_contain_only(&self, red_cubes: usize, green_cubes: usize, blue_cubes: usize) -> bool {
     self.counts.iter()
-      .map(|record| record.check_possible_contain_only(red_cubes, green_cubes, blue_cubes))
-      .all(|possible| possible)
+      .all(|record| record.check_possible_contain_only(red_cubes, green_cubes, blue_cubes))
   }
 
   fn get_minimum_playable(&self) -> (usize, usize, usize) {
@@ -82,9 +105,9 @@ impl Game {
 
     self.counts.iter()
       .for_each(|count| {
-        minimum_red = set_maximum_optional(minimum_red, count.count_red);
-        minimum_green = set_maximum_optional(minimum_green, count.count_green);
-        minimum_blue = set_maximum_optional(minimum_blue, count.count_blue);
+        minimum_red = set_maximum_optional(minimum_red, count.count(Colour::Red));
+        minimum_green = set_maximum_optional(minimum_green, count.count(Colour::Green));
+        minimum_blue = set_maximum_optional(minimum_blue, count.count(Colour::Blue));
       });
 
     (minimum_red.unwrap(), minimum_green.unwrap(), minimum_blue.unwrap())
@@ -94,4 +117,94 @@ impl Game {
     let (min_red, min_green, min_blue) = self.get_minimum_playable();
     min_red * min_green * min_blue
   }
-}
\ No newline at end of file
+}
+
+impl Solver for Puzzle {
+  type Input = Vec<Game>;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    text.lines().enumerate()
+      .map(|(line_index, line)| parse_game(line_index, line))
+      .collect()
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.iter()
+      .map(|game| {
+        let records: Vec<String> = game.counts.iter()
+          .map(|record| record.cubes.iter()
+            .map(|&(count, colour)| format!("{} {}", count, colour.name()))
+            .collect::<Vec<String>>()
+            .join(", "))
+          .collect();
+        format!("Game {}: {}", game.game_number, records.join("; "))
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    input.iter()
+      .filter(|game| game.check_possible_contain_only(12, 13, 14))
+      .map(|game| game.game_number)
+      .sum::<GameNumber>()
+      .into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    input.iter()
+      .map(|game| game.get_minimum_power_game())
+      .sum::<usize>()
+      .into()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+  #[test]
+  fn parse_game_records() {
+    let game = parse_game(0, "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red").unwrap();
+    assert_eq!(game.game_number, 3);
+    assert!(!game.check_possible_contain_only(12, 13, 14));
+    assert_eq!(game.get_minimum_power_game(), 20 * 13 * 6);
+  }
+
+  #[test]
+  fn malformed_games_are_rejected() {
+    for line in ["Game 1: 3 blue, 4 pink", "Game 1: 3 blue 4 red", "Game: 3 blue", "Game 1: 3 blue, 2 blue", "Game 1: "] {
+      assert!(parse_game(0, line).is_err(), "🚨 '{}' should not parse", line);
+    }
+  }
+
+  #[test]
+  fn errors_point_at_the_offending_token() {
+    for (line, expected_column) in [("Game 1: 3 blue, 4 pink", 19), ("Game x: 3 blue", 6), ("Game 1: 3 blue, 2 blue", 19)] {
+      match parse_game(0, line) {
+        Err(InputError::Parse { column, .. }) => assert_eq!(column, expected_column, "🚨 Wrong column for '{}'", line),
+        _ => panic!("🚨 '{}' should not parse", line),
+      }
+    }
+  }
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "8");
+  }
+
+  #[test]
+  fn example_part_two() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "2286");
+  }
+}
@@ -1,29 +1,23 @@
-// Load Local Modules
-mod read;
-
 // Imports
-use day_02::Game;
-use day_02::parse_game;
+use std::env;
 
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_02::Puzzle;
 
 fn main() {
 
-    let input = read::read_lines("input.txt".to_owned());
-    let games: Vec<Game> = input.into_iter()
-        .map(|line| parse_game(line))
-        .collect();
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let possible_games: usize = games.iter()
-        .map(|game| (game.game_number, game.check_possible_contain_only(12, 13, 14)))
-        .filter(|&(_, possible)| possible)
-        .map(|(game_number, _)| game_number)
-        .sum();
+    let possible_games = Puzzle::part_one(&input);
     println!("\r🧊 Possible games: '{}' (Part 1)", possible_games);
-    
+
     // Part 2
-    let minimum_power: usize = games.iter()
-        .map(|game| game.get_minimum_power_game())
-        .sum();
+    let minimum_power = Puzzle::part_two(&input);
     println!("\r🧊 Minimum power required: '{}' (Part 2)", minimum_power);
 }
\ No newline at end of file
@@ -1,14 +1,9 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
+use aoc_common::error::InputError;
+use aoc_common::grid::{Coordinate, Grid};
+use aoc_common::solver::{Answer, Solver};
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
-type SchemaPointUnit = i32;
-
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
-struct SchemaPoint {
-  x: SchemaPointUnit,
-  y: SchemaPointUnit,
-}
-
 pub type SchemaNumberValue = u32;
 type SchemaSymbolValue = char;
 
@@ -19,34 +14,32 @@ enum SchemaElement {
   Symbol(SchemaSymbolValue)
 }
 
-type SchemaMap = HashMap<SchemaPoint, usize>;
+type SchemaMap = Grid<usize>;
 
 pub struct Schema {
   elements: Vec<SchemaElement>,
   map: SchemaMap,
-  map_min: SchemaPoint,
-  map_max: SchemaPoint,
 }
 
+pub struct Puzzle;
+
 // =============================================== AUXILIARY FUNCTIONS ===============================================
-pub fn parse_schema(schema_unparsed: Vec<Vec<char>>) -> Schema {
+/// Schemas only hold blanks, digits and punctuation symbols, every line being as wide as the first one
+pub fn parse_schema(text: &str) -> Result<Schema, InputError> {
+  let is_schema_char = |value: char| value == '.' || value.is_ascii_digit() || value.is_ascii_punctuation();
+  let schema_unparsed: Grid<char> = Grid::parse_text(text, |value| is_schema_char(value).then_some(value))?;
   let mut elements: Vec<SchemaElement> = Vec::new();
-  let mut map: SchemaMap = HashMap::new();
+  let mut cells: Vec<usize> = Vec::new();
 
   elements.push(SchemaElement::Blank);
 
-  let map_min: SchemaPoint = SchemaPoint::new(0, 0);
-  let map_max: SchemaPoint = SchemaPoint::new(schema_unparsed.len() as SchemaPointUnit, schema_unparsed.get(0).unwrap().len() as SchemaPointUnit);
-
-  for (row_index, row) in schema_unparsed.into_iter().enumerate() {
+  for (line_index, row) in schema_unparsed.rows().enumerate() {
 
     let mut current_value: Option<SchemaNumberValue> = None;
-    for (column_index, value) in row.into_iter().enumerate() {
+    for (column_index, &value) in row.iter().enumerate() {
 
-      let current_point = SchemaPoint::new(column_index as SchemaPointUnit, row_index as SchemaPointUnit);
-      
       // Check if building current value and whether to keep building or stop
-      if current_value.is_some() && !value.is_digit(10) {
+      if current_value.is_some() && !value.is_ascii_digit() {
         let schema_element: SchemaElement = SchemaElement::Number(current_value.unwrap());
         elements.push(schema_element);
         current_value = None;
@@ -55,15 +48,19 @@ pub fn parse_schema(schema_unparsed: Vec<Vec<char>>) -> Schema {
       match value {
         '.' => {
           // Insert into map the blank element;s position
-          map.insert(current_point, 0);
+          cells.push(0);
         },
 
-        digit if digit.is_digit(10) => {
+        digit if digit.is_ascii_digit() => {
           // Update the schema element
           let digit_parsed: SchemaNumberValue = digit.to_digit(10).unwrap();
-          current_value = Some(current_value.map_or(digit_parsed, |value| value * 10 + digit_parsed));
+          let number = current_value.map_or(Some(digit_parsed), |value| value.checked_mul(10)
+            .and_then(|value| value.checked_add(digit_parsed)));
+          let too_large = || InputError::parse(line_index + 1, column_index + 1, text.lines().nth(line_index).unwrap_or(""),
+            "part number is too large");
+          current_value = Some(number.ok_or_else(too_large)?);
           // Insert into map the new schema element's position
-          map.insert(current_point, elements.len());
+          cells.push(elements.len());
         },
 
         symbol => {
@@ -71,56 +68,57 @@ pub fn parse_schema(schema_unparsed: Vec<Vec<char>>) -> Schema {
           let schema_element: SchemaElement = SchemaElement::Symbol(symbol);
           elements.push(schema_element);
           // Insert into map the new schema element's position
-          map.insert(current_point, elements.len() - 1);
+          cells.push(elements.len() - 1);
         } 
       };
 
     }
 
-    if current_value.is_some() {
-      let schema_element: SchemaElement = SchemaElement::Number(current_value.unwrap());
+    if let Some(current_value) = current_value {
+      let schema_element: SchemaElement = SchemaElement::Number(current_value);
       elements.push(schema_element);
     }
   }
 
-
-  Schema { elements, map, map_min, map_max }
+  let map: SchemaMap = Grid::new(schema_unparsed.width(), schema_unparsed.height(), cells);
+  Ok(Schema { elements, map })
 }
 
 // ================================================= IMPLEMENTATIONS =================================================
-impl SchemaPoint {
-
-  fn new(x: SchemaPointUnit, y: SchemaPointUnit) -> SchemaPoint {
-    SchemaPoint { x, y }
-  }
-}
-
-impl std::ops::Add for SchemaPoint {
-  type Output = SchemaPoint;
+impl Schema {
 
-  fn add(self, other: SchemaPoint) -> SchemaPoint {
-    let new_x: SchemaPointUnit = self.x + other.x;
-    let new_y: SchemaPointUnit = self.y + other.y;
-    
-    SchemaPoint::new(new_x, new_y)
+  /// Number cells point to the whole number, so each run of them is written back padded to its width to keep
+  /// any leading zero
+  fn render_row(&self, row: &[usize]) -> String {
+    let mut line = String::with_capacity(row.len());
+    let mut cells = row.iter().peekable();
+    while let Some(&index) = cells.next() {
+      match self.elements[index] {
+        SchemaElement::Blank => line.push('.'),
+        SchemaElement::Symbol(symbol) => line.push(symbol),
+        SchemaElement::Number(value) => {
+          let mut width = 1;
+          while cells.next_if_eq(&&index).is_some() { width += 1 }
+          line.push_str(&format!("{:0width$}", value, width = width));
+        },
+      }
+    }
+    line
   }
-}
-
-impl Schema {
 
-  fn get_symbol_positions(&self) -> Vec<SchemaPoint> {
+  fn get_symbol_positions(&self) -> Vec<Coordinate> {
     self.map.iter()
       .filter(|&(_, &value)| matches!(self.elements.get(value).unwrap(), SchemaElement::Symbol(_)))
-      .map(|(&position, _)| position)
+      .map(|(position, _)| position)
       .collect()
   }
 
-  fn get_gear_positions(&self) -> Vec<SchemaPoint> {
+  fn get_gear_positions(&self) -> Vec<Coordinate> {
     self.map.iter()
       .filter_map(|(position, &value)| {
         let elem = self.elements.get(value).unwrap();
         match elem {
-          SchemaElement::Symbol('*') => Some(*position),
+          SchemaElement::Symbol('*') => Some(position),
           _ => None
         }
 
@@ -128,27 +126,16 @@ impl Schema {
       .collect()
   }
 
-  fn get_surround_positions(&self, position: SchemaPoint) -> Vec<SchemaPoint> {
-    let mut surround_positions: Vec<SchemaPoint> = Vec::new();
-    for row_delta in -1..=1 {
-      for column_delta in -1..=1 {
-        if row_delta == 0 && column_delta == 0 { continue; }
-
-        let delta = SchemaPoint::new(column_delta, row_delta);
-        surround_positions.push(position + delta);
-      }
-    }
-
-    surround_positions
+  fn get_surround_positions(&self, position: Coordinate) -> Vec<Coordinate> {
+    self.map.neighbours_8(position).collect()
   }
 
   pub fn find_part_numbers(&self) -> Vec<SchemaNumberValue> {
     // Get symbol positions
     let symbol_positions = self.get_symbol_positions();
     // Get all surround positions of symbol positions
-    let check_positions: HashSet<SchemaPoint> = symbol_positions.into_iter()
-      .map(|symbol_position| self.get_surround_positions(symbol_position))
-      .flatten()
+    let check_positions: HashSet<Coordinate> = symbol_positions.into_iter()
+      .flat_map(|symbol_position| self.get_surround_positions(symbol_position))
       .collect();
 
     // Get element indexes from check positions
@@ -171,7 +158,7 @@ impl Schema {
     // Get gear positions
     let symbol_positions = self.get_gear_positions();
     // Get all surround positions of gear positions
-    let check_positions: Vec<Vec<SchemaPoint>> = symbol_positions.into_iter()
+    let check_positions: Vec<Vec<Coordinate>> = symbol_positions.into_iter()
       .map(|symbol_position| self.get_surround_positions(symbol_position))
       .collect();
 
@@ -194,7 +181,77 @@ impl Schema {
         })
         .collect::<Vec<SchemaNumberValue>>())
       .filter(|part_numbers| part_numbers.len() == 2)
-      .map(|part_values| part_values.get(0).unwrap() * part_values.get(1).unwrap())
+      .map(|part_values| part_values.first().unwrap() * part_values.get(1).unwrap())
       .collect()
   }
-}
\ No newline at end of file
+}
+
+impl Solver for Puzzle {
+  type Input = Schema;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    parse_schema(text)
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.map.rows()
+      .map(|row| input.render_row(row))
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    input.find_part_numbers().into_iter()
+      .sum::<SchemaNumberValue>()
+      .into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    input.find_gear_ratios().into_iter()
+      .sum::<SchemaNumberValue>()
+      .into()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "4361");
+  }
+
+  #[test]
+  fn example_part_two() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "467835");
+  }
+
+  #[test]
+  fn gear_on_the_border_of_the_schema() {
+    let input = Puzzle::parse("*12\n3..").unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "36");
+  }
+
+  #[test]
+  fn leading_zeros_are_kept() {
+    let text = "007*.\n..#10";
+    assert_eq!(Puzzle::render(&Puzzle::parse(text).unwrap()), text);
+    assert!(Puzzle::parse("12 *\n....").is_err());
+  }
+}
@@ -1,26 +1,23 @@
-
-// Load Local Modules
-mod read;
-
 // Imports
-use day_03::SchemaNumberValue;
-use day_03::parse_schema;
+use std::env;
 
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_03::Puzzle;
 
 fn main() {
 
-    let input = read::read_chars("input.txt".to_owned());
-    let schema = parse_schema(input);
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let sum_part_values: SchemaNumberValue = schema.find_part_numbers()
-        .into_iter()
-        .sum();
+    let sum_part_values = Puzzle::part_one(&input);
     println!("\r🚇 Sum of part values: '{}' (Part 1)", sum_part_values);
 
     // Part 2
-    let sum_gear_ratios: SchemaNumberValue = schema.find_gear_ratios()
-        .into_iter()
-        .sum();
+    let sum_gear_ratios = Puzzle::part_two(&input);
     println!("\r🚇 Sum of gear ratios: '{}' (Part 2)", sum_gear_ratios);
 }
\ No newline at end of file
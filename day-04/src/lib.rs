@@ -1,42 +1,44 @@
 use std::collections::{HashSet, HashMap};
+use aoc_common::error::InputError;
+use aoc_common::solver::{Answer, Solver};
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 type CardNumber = u32;
 type Number = u32;
 
+/// Numbers as listed on the card
 pub struct Card {
-  winning_numbers: HashSet<Number>,
-  drawn_numbers: HashSet<Number>
+  winning_numbers: Vec<Number>,
+  drawn_numbers: Vec<Number>
 }
 
 pub type Deck = HashMap<CardNumber, Card>;
 
+pub struct Puzzle;
+
 // =============================================== AUXILIARY FUNCTIONS ===============================================
-pub fn parse_card(line: String) -> (CardNumber, Card) {
-  // Split on ': ' to achieve ['Card X', '<winning-numbers> | '<drawn-numbers>']
-  let mut information_splitted: Vec<&str> = line.split(": ").collect();
-  let number: CardNumber = information_splitted.remove(0)
-    .replace("Card", "")
-    .trim()
-    .parse().unwrap();
-
-  // Split on ' | ' to achieve ['<winning-numbers>', '<drawn-numbers>']
-  let mut information_splitted: Vec<&str> = information_splitted.remove(0)
-    .split(" | ")
-    .collect();
-  let winning_numbers: HashSet<Number> = information_splitted.remove(0)
-    .split_whitespace()
-    .into_iter()
-    .map(|number| number.parse().unwrap())
-    .collect();
-  let drawn_numbers: HashSet<Number> = information_splitted.remove(0)
-    .split_whitespace()
-    .into_iter()
-    .map(|number| number.parse().unwrap())
-    .collect();
+/// Cards must come in order, 'Card 1' first, so every copy won lands on an existing card
+pub fn parse_card(line_index: usize, line: &str) -> Result<(CardNumber, Card), InputError> {
+  let error = |token: &str, reason: &str| InputError::at_token(line_index + 1, line, token, reason);
+  let parse_numbers = |numbers: &str| numbers.split_whitespace()
+    .map(|number| number.parse().map_err(|_| error(number, "card holds something other than numbers")))
+    .collect::<Result<Vec<Number>, InputError>>();
 
-  let card = Card { winning_numbers, drawn_numbers };
-  ( number, card )
+  // Split on ':' to achieve ['Card X', '<winning-numbers> | <drawn-numbers>']
+  let (header, numbers) = line.strip_prefix("Card ").and_then(|line| line.split_once(':'))
+    .ok_or_else(|| error(line, "card is not formatted as 'Card number: winning | drawn'"))?;
+  let header = header.trim();
+  let number: CardNumber = header.parse().map_err(|_| error(header, "card number is not a number"))?;
+  if number as usize != line_index + 1 {
+    return Err(error(header, "cards are not numbered in order"));
+  }
+
+  // Split on '|' to achieve ['<winning-numbers>', '<drawn-numbers>']
+  let (winning_numbers, drawn_numbers) = numbers.split_once('|')
+    .ok_or_else(|| error(numbers, "card does not split winning and drawn numbers with '|'"))?;
+
+  let card = Card { winning_numbers: parse_numbers(winning_numbers)?, drawn_numbers: parse_numbers(drawn_numbers)? };
+  Ok((number, card))
 }
 
 pub fn count_cards(card_deck: &Deck) -> usize {
@@ -60,15 +62,16 @@ pub fn count_cards(card_deck: &Deck) -> usize {
       let card = card_deck.get(&number).unwrap();
       let card_value = card.get_matching_numbers().len() as u32;
 
+      // Cards past the end of the table are not won
       for number_to_update in (number + 1)..=(number + card_value) {
-        let update_number = counter_map.get_mut(&number_to_update).unwrap();
-        *update_number = *update_number + copies as usize;
+        if let Some(update_number) = counter_map.get_mut(&number_to_update) {
+          *update_number += copies;
+        }
       }
     });
 
   // Count total number of cards
-  counter_map.into_iter()
-    .map(|(_, count)| count)
+  counter_map.into_values()
     .sum()
 }
 
@@ -76,7 +79,9 @@ pub fn count_cards(card_deck: &Deck) -> usize {
 impl Card {
 
   fn get_matching_numbers(&self) -> HashSet<Number> {
-    self.drawn_numbers.intersection(&self.winning_numbers)
+    let winning_numbers: HashSet<&Number> = self.winning_numbers.iter().collect();
+    self.drawn_numbers.iter()
+      .filter(|number| winning_numbers.contains(number))
       .map(|number| number.to_owned())
       .collect()
   }
@@ -86,7 +91,89 @@ impl Card {
 
     match count_matching_numbers {
       0 => 0,
-      number => (2 as u32).pow(number - 1)
+      number => 2_u32.pow(number - 1)
+    }
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = Deck;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    text.lines().enumerate()
+      .map(|(line_index, line)| parse_card(line_index, line))
+      .collect()
+  }
+
+  fn render(input: &Self::Input) -> String {
+    let format_numbers = |numbers: &[Number]| numbers.iter()
+      .map(|number| number.to_string())
+      .collect::<Vec<String>>()
+      .join(" ");
+
+    (1..=input.len() as CardNumber)
+      .map(|number| {
+        let card = &input[&number];
+        format!("Card {}: {} | {}", number, format_numbers(&card.winning_numbers), format_numbers(&card.drawn_numbers))
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    input.values()
+      .map(|card| card.get_card_value())
+      .sum::<u32>()
+      .into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    count_cards(input).into()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+  #[test]
+  fn card_value_doubles_with_each_match() {
+    let (number, card) = parse_card(0, "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+    assert_eq!(number, 1);
+    assert_eq!(card.get_card_value(), 8);
+  }
+
+  #[test]
+  fn malformed_cards_are_rejected() {
+    for line in ["Card 1: 41 48 83 86 17 83 86", "Card 2: 41 | 83", "Card 1 41 | 83", "Card 1: 4a | 83"] {
+      assert!(parse_card(0, line).is_err(), "🚨 '{}' should not parse", line);
     }
   }
-}
\ No newline at end of file
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "13");
+  }
+
+  #[test]
+  fn example_part_two() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "30");
+  }
+
+  #[test]
+  fn copies_past_the_table_are_not_won() {
+    let input = Puzzle::parse("Card 1: 1 2 | 1 2").unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "1");
+  }
+}
@@ -1,27 +1,23 @@
-
-// Load Local Modules
-mod read;
-
 // Imports
-use day_04::Deck;
-use day_04::count_cards;
-use day_04::parse_card;
+use std::env;
 
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_04::Puzzle;
 
 fn main() {
 
-    let input = read::read_lines("input.txt".to_owned());
-    let cards: Deck = input.into_iter()
-        .map(|line| parse_card(line))
-        .collect();
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let sum_card_values: u32 = cards.iter()
-        .map(|(_, card)| card.get_card_value())
-        .sum();
+    let sum_card_values = Puzzle::part_one(&input);
     println!("\r🃏 Sum of part values: '{}' (Part 1)", sum_card_values);
 
     // Part 2
-    let number_cards: usize = count_cards(&cards);
+    let number_cards = Puzzle::part_two(&input);
     println!("\r🃏 Number of cards: '{}' (Part 2)", number_cards);
 }
\ No newline at end of file
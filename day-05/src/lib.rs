@@ -1,3 +1,7 @@
+use aoc_common::error::InputError;
+use aoc_common::range::RangeItem;
+use aoc_common::solver::{Answer, Solver};
+
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 pub type EntityID = u64;
 
@@ -22,12 +26,6 @@ pub struct Almanac {
   humidity_to_location: EntityMap,
 }
 
-#[derive(Clone)]
-pub struct RangeItem {
-  pub range_start: EntityID,
-  pub range_end: EntityID,
-}
-
 type Range = Vec<RangeItem>;
 
 pub struct AlmanacRange {
@@ -41,95 +39,107 @@ pub struct AlmanacRange {
   humidity_to_location: EntityMap,
 }
 
+pub struct Puzzle;
+
+// ==================================================== CONSTANTS ====================================================
+const MAP_COUNT: usize = 7;
+/// Names of the maps in the order the almanac lists them, from seeds down to locations
+pub const MAP_NAMES: [&str; MAP_COUNT] = [
+  "seed-to-soil", "soil-to-fertilizer", "fertilizer-to-water", "water-to-light",
+  "light-to-temperature", "temperature-to-humidity", "humidity-to-location"];
+
 // =============================================== AUXILIARY FUNCTIONS ===============================================
-fn parse_entity_map_entry(line: &str) -> EntityMapEntry {
-  let mut ids: Vec<EntityID> = line.split_whitespace()
-    .into_iter()
-    .map(|id| id.parse().unwrap())
-    .collect();
+fn parse_ids(line_index: usize, line: &str, ids: &str) -> Result<Vec<EntityID>, InputError> {
+  ids.split_whitespace()
+    .map(|id| id.parse().map_err(|_| InputError::at_token(line_index + 1, line, id, "id is not a number")))
+    .collect()
+}
 
-  EntityMapEntry {
-    destination_start: ids.remove(0),
-    source_start: ids.remove(0),
-    range_size: ids.remove(0)
+fn parse_entity_map_entry(line_index: usize, line: &str) -> Result<EntityMapEntry, InputError> {
+  let error = |token: &str, reason: &str| InputError::at_token(line_index + 1, line, token, reason);
+  let [destination_start, source_start, range_size] = parse_ids(line_index, line, line)?[..] else {
+    return Err(error(line, "map entry is not formatted as 'destination source size'"));
+  };
+  if range_size == 0 || destination_start.checked_add(range_size).is_none() || source_start.checked_add(range_size).is_none() {
+    let size = line.split_whitespace().last().unwrap_or(line);
+    return Err(error(size, "map entry range is empty or goes past the largest id"));
   }
+
+  Ok(EntityMapEntry { destination_start, source_start, range_size })
 }
 
-fn parse_entity_map(mut lines: Vec<&str>) -> EntityMap {
-  let _ = lines.remove(0);
-  let maps = lines.into_iter()
-    .map(|line| parse_entity_map_entry(line))
-    .collect();
+/// Seeds and every map in the order of 'MAP_NAMES', sections being separated by empty lines
+fn parse_sections(text: &str) -> Result<(Vec<EntityID>, [EntityMap; MAP_COUNT]), InputError> {
+  let mut lines = text.lines().enumerate();
+  let (_, seeds_line) = lines.next().unwrap_or((0, ""));
+  let seed_ids = seeds_line.strip_prefix("seeds: ")
+    .ok_or_else(|| InputError::parse(1, 1, seeds_line, "almanac does not start with 'seeds: '"))?;
+  let seeds = parse_ids(0, seeds_line, seed_ids)?;
+  if seeds.is_empty() {
+    return Err(InputError::at_token(1, seeds_line, seed_ids, "almanac has no seeds"));
+  }
+
+  let mut maps: Vec<EntityMap> = Vec::new();
+  for (line_index, line) in lines {
+    if line.is_empty() { continue }
 
-  EntityMap { maps }
+    match line.strip_suffix(" map:") {
+      Some(name) if MAP_NAMES.get(maps.len()) == Some(&name) => maps.push(EntityMap { maps: Vec::new() }),
+      Some(_) => return Err(InputError::parse(line_index + 1, 1, line, "map is not the one expected next")),
+      None => maps.last_mut()
+        .ok_or_else(|| InputError::parse(line_index + 1, 1, line, "map entry comes before any map"))?
+        .maps.push(parse_entity_map_entry(line_index, line)?),
+    }
+  }
+
+  let missing = maps.len();
+  let maps = maps.try_into().map_err(|_| {
+    let line_count = text.lines().count();
+    InputError::parse(line_count + 1, 1, MAP_NAMES[missing], "almanac is missing a map")
+  })?;
+  Ok((seeds, maps))
 }
 
-pub fn parse_almanac(lines: &Vec<String>) -> Almanac {
-  let mut almanac_groups: Vec<Vec<&str>> = lines.split(|line| line.is_empty())
-    .into_iter()
-    .map(|group| group.into_iter()
-      .map(|line| line.as_ref())
-      .collect())
-    .collect();
-
-  let seeds: Vec<EntityID> = almanac_groups.remove(0)
-    .remove(0)
-    .strip_prefix("seeds: ").unwrap()
-    .split_whitespace()
-    .map(|seed_number| seed_number.parse().unwrap())
-    .collect();
-
-  let seed_to_soil = parse_entity_map(almanac_groups.remove(0));
-  let soil_to_fertilizer = parse_entity_map(almanac_groups.remove(0));
-  let fertilizer_to_water = parse_entity_map(almanac_groups.remove(0));
-  let water_to_light = parse_entity_map(almanac_groups.remove(0));
-  let light_to_temperature = parse_entity_map(almanac_groups.remove(0));
-  let temperature_to_humidity = parse_entity_map(almanac_groups.remove(0));
-  let humidity_to_location = parse_entity_map(almanac_groups.remove(0));
-
-  Almanac {
+pub fn parse_almanac(text: &str) -> Result<Almanac, InputError> {
+  let (seeds, maps) = parse_sections(text)?;
+  let [
+    seed_to_soil, soil_to_fertilizer,
+    fertilizer_to_water, water_to_light,
+    light_to_temperature, temperature_to_humidity, humidity_to_location] = maps;
+
+  Ok(Almanac {
     seeds,
     seed_to_soil, soil_to_fertilizer,
     fertilizer_to_water, water_to_light,
-    light_to_temperature, temperature_to_humidity, humidity_to_location }
+    light_to_temperature, temperature_to_humidity, humidity_to_location })
 }
 
-pub fn parse_almanac_range(lines: &Vec<String>) -> AlmanacRange {
-  let mut almanac_groups: Vec<Vec<&str>> = lines.split(|line| line.is_empty())
-    .into_iter()
-    .map(|group| group.into_iter()
-      .map(|line| line.as_ref())
-      .collect())
-    .collect();
-
-  let seed_ids: Vec<EntityID> = almanac_groups.remove(0)
-    .remove(0)
-    .strip_prefix("seeds: ").unwrap()
-    .split_whitespace()
-    .map(|seed_number| seed_number.parse().unwrap())
-    .collect();
-  let seeds: Range = seed_ids.chunks(2)
-    .into_iter()
-    .map(|chunk| {
-      let range_start = *chunk.get(0).unwrap();
-      let range_end = range_start + *chunk.get(1).unwrap() - 1;
-
-      RangeItem { range_start, range_end }})
-    .collect();
-
-  let seed_to_soil = parse_entity_map(almanac_groups.remove(0));
-  let soil_to_fertilizer = parse_entity_map(almanac_groups.remove(0));
-  let fertilizer_to_water = parse_entity_map(almanac_groups.remove(0));
-  let water_to_light = parse_entity_map(almanac_groups.remove(0));
-  let light_to_temperature = parse_entity_map(almanac_groups.remove(0));
-  let temperature_to_humidity = parse_entity_map(almanac_groups.remove(0));
-  let humidity_to_location = parse_entity_map(almanac_groups.remove(0));
-
-  AlmanacRange {
+/// Seeds come in pairs of a start and a length, which must stay within the ids
+pub fn parse_almanac_range(text: &str) -> Result<AlmanacRange, InputError> {
+  let (seed_ids, maps) = parse_sections(text)?;
+  let seeds_line = text.lines().next().unwrap_or("");
+  let seed_tokens: Vec<&str> = seeds_line.split_whitespace().skip(1).collect();
+  let seeds_error = |token: &str, reason: &str| InputError::at_token(1, seeds_line, token, reason);
+  let seeds: Range = seed_ids.chunks(2).zip(seed_tokens.chunks(2))
+    .map(|(chunk, tokens)| match chunk {
+      &[range_start, range_size] => range_size.checked_sub(1)
+        .and_then(|range_size| range_start.checked_add(range_size))
+        .map(|range_end| RangeItem { range_start, range_end })
+        .ok_or_else(|| seeds_error(tokens[1], "seed range is empty or goes past the largest id")),
+      _ => Err(seeds_error(tokens[0], "seeds do not come in pairs of a start and a length")),
+    })
+    .collect::<Result<_, _>>()?;
+
+  let [
+    seed_to_soil, soil_to_fertilizer,
+    fertilizer_to_water, water_to_light,
+    light_to_temperature, temperature_to_humidity, humidity_to_location] = maps;
+
+  Ok(AlmanacRange {
     seeds,
     seed_to_soil, soil_to_fertilizer,
     fertilizer_to_water, water_to_light,
-    light_to_temperature, temperature_to_humidity, humidity_to_location }
+    light_to_temperature, temperature_to_humidity, humidity_to_location })
 }
 
 // ================================================= IMPLEMENTATIONS =================================================
@@ -148,34 +158,13 @@ impl EntityMapEntry {
   }
 
   fn match_range(&self, range: RangeItem) -> (Option<RangeItem>, Option<RangeItem>, Option<RangeItem>) {
-    let mut before_range: Option<RangeItem> = None;
-    let mut between_range: Option<RangeItem> = None;
-    let mut after_range: Option<RangeItem> = None;
-
-    // Check if there is a range out of scope before
-    if range.range_start < self.source_start {
-      before_range = Some(RangeItem {
-        range_start: range.range_start,
-        range_end: EntityID::min(self.source_start - 1, range.range_end) });
-    }
-
-    // Check if there is a range in between the map item
-    let range_outside = (range.range_end < self.source_start) || (range.range_start >= (self.source_start + self.range_size));
-    if !range_outside {
-      let between_source_start = EntityID::max(self.source_start, range.range_start);
-      let between_source_end = EntityID::min(self.source_start + self.range_size - 1, range.range_end);
+    let source_range = RangeItem::new(self.source_start, self.source_start + self.range_size - 1);
+    let (before_range, between_range, after_range) = range.split(source_range);
 
-      between_range = Some(RangeItem {
-        range_start: self.convert_source_to_dest(between_source_start),
-        range_end: self.convert_source_to_dest(between_source_end) });
-    }
-
-    // Check if there is a range out of scope after
-    if range.range_end >= (self.source_start + self.range_size) {
-      after_range = Some(RangeItem {
-        range_start: EntityID::max(self.source_start + self.range_size, range.range_start),
-        range_end: range.range_end });
-    }
+    // Only the part in between the map item gets converted
+    let between_range = between_range.map(|between| RangeItem::new(
+      self.convert_source_to_dest(between.range_start),
+      self.convert_source_to_dest(between.range_end)));
 
     (before_range, between_range, after_range)
   }
@@ -199,10 +188,10 @@ impl EntityMap {
           .flat_map(|unparsed_range_item| {
             let (before, between, after) = map.match_range(unparsed_range_item);
 
-            between.map(|between| parsed_range.push(between));
+            if let Some(between) = between { parsed_range.push(between) }
 
             vec![before, after].into_iter()
-              .filter_map(|range_item| range_item)
+              .flatten()
           })
           .collect::<Vec<RangeItem>>()
       });
@@ -214,9 +203,16 @@ impl EntityMap {
 
 impl Almanac {
 
+  fn maps(&self) -> [&EntityMap; MAP_COUNT] {
+    [
+      &self.seed_to_soil, &self.soil_to_fertilizer,
+      &self.fertilizer_to_water, &self.water_to_light,
+      &self.light_to_temperature, &self.temperature_to_humidity, &self.humidity_to_location]
+  }
+
   pub fn convert_seeds_to_locations(&self) -> Vec<EntityID> {
 
-    fn convert_ids(ids: &Vec<EntityID>, map: &EntityMap) -> Vec<EntityID> {
+    fn convert_ids(ids: &[EntityID], map: &EntityMap) -> Vec<EntityID> {
       ids.iter().map(|&id| map.match_source(id)).collect()
     }
 
@@ -226,9 +222,7 @@ impl Almanac {
     let lights = convert_ids(&waters, &self.water_to_light);
     let temperatures = convert_ids(&lights, &self.light_to_temperature);
     let humidities = convert_ids(&temperatures, &self.temperature_to_humidity);
-    let locations = convert_ids(&humidities, &self.humidity_to_location);
-
-    locations
+    convert_ids(&humidities, &self.humidity_to_location)
   }
 }
 
@@ -242,8 +236,172 @@ impl AlmanacRange {
     let lights = self.water_to_light.match_range(waters);
     let temperatures = self.light_to_temperature.match_range(lights);
     let humidities = self.temperature_to_humidity.match_range(temperatures);
-    let locations = self.humidity_to_location.match_range(humidities);
+    self.humidity_to_location.match_range(humidities)
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = (Almanac, AlmanacRange);
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    Ok((parse_almanac(text)?, parse_almanac_range(text)?))
+  }
+
+  fn render(input: &Self::Input) -> String {
+    let seeds: Vec<String> = input.0.seeds.iter().map(|seed| seed.to_string()).collect();
+    let maps = MAP_NAMES.iter().zip(input.0.maps())
+      .map(|(name, map)| std::iter::once(format!("{} map:", name))
+        .chain(map.maps.iter()
+          .map(|entry| format!("{} {} {}", entry.destination_start, entry.source_start, entry.range_size)))
+        .collect::<Vec<String>>()
+        .join("\n"));
+
+    std::iter::once(format!("seeds: {}", seeds.join(" ")))
+      .chain(maps)
+      .collect::<Vec<String>>()
+      .join("\n\n")
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    input.0.convert_seeds_to_locations().into_iter()
+      .min().unwrap()
+      .into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    input.1.convert_seeds_to_locations().into_iter()
+      .map(|range_item| range_item.range_start)
+      .min().unwrap()
+      .into()
+  }
+}
 
-    locations
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use aoc_common::random::{differential, Random};
+
+  const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+  #[test]
+  fn match_range_splits_around_the_entry() {
+    let entry = EntityMapEntry { destination_start: 100, source_start: 10, range_size: 5 };
+    let (before, between, after) = entry.match_range(RangeItem { range_start: 5, range_end: 20 });
+
+    let before = before.unwrap();
+    let between = between.unwrap();
+    let after = after.unwrap();
+    assert_eq!((before.range_start, before.range_end), (5, 9));
+    assert_eq!((between.range_start, between.range_end), (100, 104));
+    assert_eq!((after.range_start, after.range_end), (15, 20));
+  }
+
+  #[test]
+  fn malformed_almanacs_are_rejected() {
+    let missing_map = &EXAMPLE[..EXAMPLE.find("\n\nhumidity-to-location").unwrap()];
+    let overflowing_entry = EXAMPLE.replace("60 56 37", "60 18446744073709551600 37");
+    let odd_seeds = EXAMPLE.replace("seeds: 79 14 55 13", "seeds: 79 14 55");
+    let swapped_maps = EXAMPLE.replace("seed-to-soil", "soil-to-seed");
+    for text in [missing_map, &overflowing_entry, &odd_seeds, &swapped_maps] {
+      assert!(Puzzle::parse(text).is_err(), "🚨 Almanac should not parse:\n{}", text);
+    }
   }
-}
\ No newline at end of file
+
+  #[test]
+  fn example_seed_locations() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(input.0.convert_seeds_to_locations(), vec![82, 43, 86, 35]);
+  }
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "35");
+  }
+
+  #[test]
+  fn example_part_two() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "46");
+  }
+
+  /// Small almanac whose map entries never overlap, as in the real inputs
+  fn random_almanac(random: &mut Random) -> String {
+    let seeds: Vec<String> = (0..random.range(1, 3))
+      .map(|_| format!("{} {}", random.below(60), random.range(1, 20)))
+      .collect();
+
+    let maps: Vec<String> = MAP_NAMES.iter()
+      .map(|name| {
+        let mut entries: Vec<String> = Vec::new();
+        let mut source_start = random.below(10);
+        for _ in 0..random.range(1, 4) {
+          let range_size = random.range(1, 20);
+          entries.push(format!("{} {} {}", random.below(80), source_start, range_size));
+          source_start += range_size + random.below(10);
+        }
+        random.shuffle(&mut entries);
+        format!("{} map:\n{}", name, entries.join("\n"))
+      })
+      .collect();
+
+    format!("seeds: {}\n\n{}", seeds.join(" "), maps.join("\n\n"))
+  }
+
+  /// Reference for the range splitting: every seed of every range walked through the maps one at a time
+  fn naive_lowest_location(almanac: &AlmanacRange) -> EntityID {
+    let maps = [
+      &almanac.seed_to_soil, &almanac.soil_to_fertilizer, &almanac.fertilizer_to_water, &almanac.water_to_light,
+      &almanac.light_to_temperature, &almanac.temperature_to_humidity, &almanac.humidity_to_location];
+
+    almanac.seeds.iter()
+      .flat_map(|seeds| seeds.range_start..=seeds.range_end)
+      .map(|seed| maps.iter().fold(seed, |id, map| {
+        map.maps.iter()
+          .find(|entry| (entry.source_start..entry.source_start + entry.range_size).contains(&id))
+          .map_or(id, |entry| entry.destination_start + (id - entry.source_start))
+      }))
+      .min().unwrap()
+  }
+
+  #[test]
+  fn range_splitting_matches_naive_conversion() {
+    differential(300, random_almanac,
+      |text| naive_lowest_location(&Puzzle::parse(text).unwrap().1).to_string(),
+      |text| Puzzle::part_two(&Puzzle::parse(text).unwrap()).to_string());
+  }
+}
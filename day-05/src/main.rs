@@ -1,27 +1,23 @@
-// Load Local Modules
-mod read;
-
 // Imports
-use day_05::EntityID;
-use day_05::parse_almanac;
-use day_05::parse_almanac_range;
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_05::Puzzle;
 
 fn main() {
 
-    let input = read::read_lines("input.txt".to_owned());
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let almanac = parse_almanac(&input);
-    let lowest_location: EntityID = almanac.convert_seeds_to_locations()
-        .into_iter()
-        .min().unwrap();
+    let lowest_location = Puzzle::part_one(&input);
     println!("\r🌱 Lowest location value (basic version): '{}' (Part 1)", lowest_location);
 
     // Part 2
-    let almanac_range = parse_almanac_range(&input);
-    let lowest_location: EntityID = almanac_range.convert_seeds_to_locations()
-        .into_iter()
-        .map(|range_item| range_item.range_start)
-        .min().unwrap();
+    let lowest_location = Puzzle::part_two(&input);
     println!("\r🌱 Lowest location value (range version): '{}' (Part 2)", lowest_location);
 }
\ No newline at end of file
@@ -1,8 +1,9 @@
 use std::str::FromStr;
-use std::fmt::Debug;
 
 use roots::Roots;
 use roots::find_roots_quadratic;
+use aoc_common::error::InputError;
+use aoc_common::solver::{Answer, Solver};
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 pub type TimeUnit = u64;
@@ -15,78 +16,96 @@ pub struct Race {
 
 type RaceSolution = Option<(TimeUnit, TimeUnit)>;
 
+pub struct Puzzle;
+
 // =============================================== AUXILIARY FUNCTIONS ===============================================
-pub fn parse_races(lines: &Vec<String>) -> Vec<Race> {
+/// The two lines of the sheet, checked to be exactly the times then the distances
+fn sheet_lines(text: &str) -> Result<[(usize, &str, &str); 2], InputError> {
+  let lines: Vec<&str> = text.lines().collect();
+  if lines.len() != 2 {
+    let line_index = lines.len().min(2);
+    return Err(InputError::parse(line_index + 1, 1, lines.get(line_index).copied().unwrap_or(""),
+      "sheet is not a line of times followed by a line of distances"));
+  }
+
+  let mut sheet = [(0, "", ""); 2];
+  for (line_index, prefix) in ["Time:", "Distance:"].into_iter().enumerate() {
+    let line = lines[line_index];
+    let values = line.strip_prefix(prefix)
+      .ok_or_else(|| InputError::parse(line_index + 1, 1, line, format!("line does not start with '{}'", prefix)))?;
+    sheet[line_index] = (line_index, line, values);
+  }
+  Ok(sheet)
+}
+
+pub fn parse_races(text: &str) -> Result<Vec<Race>, InputError> {
 
   // Parse values from each line agnostically
-  fn parse_values<T>(line: &str, prefix: &str) -> Vec<T>
+  fn parse_values<T>((line_index, line, values): (usize, &str, &str)) -> Result<Vec<T>, InputError>
   where
     T: FromStr,
-    <T as FromStr>::Err: Debug
   {
-    line.strip_prefix(prefix).unwrap()
-      .trim()
-      .split_whitespace()
-      .map(|time| time.parse().unwrap())
+    values.split_whitespace()
+      .map(|value| value.parse().map_err(|_| InputError::at_token(line_index + 1, line, value, "value is not a number")))
       .collect()
   }
 
-  let times: Vec<TimeUnit> = parse_values(lines.get(0).unwrap(), "Time:");
-  let distances: Vec<DistanceUnit> = parse_values(lines.get(1).unwrap(), "Distance:");
+  let [time_line, distance_line] = sheet_lines(text)?;
+  let times: Vec<TimeUnit> = parse_values(time_line)?;
+  let distances: Vec<DistanceUnit> = parse_values(distance_line)?;
+  if times.is_empty() || times.len() != distances.len() {
+    let (_, line, values) = distance_line;
+    return Err(InputError::at_token(2, line, values.trim_start(), "races do not have one time and one distance each"));
+  }
 
-  times.into_iter().zip(distances.into_iter())
-    .into_iter()
+  Ok(times.into_iter().zip(distances)
     .map(|(allocated_time, record_distance)| Race{ allocated_time, record_distance })
-    .collect()
+    .collect())
 }
 
-pub fn parse_race_kerning(lines: &Vec<String>) -> Race {
+pub fn parse_race_kerning(text: &str) -> Result<Race, InputError> {
 
   // Parse values from each line agnostically
-  fn parse_value<T>(line: &str, prefix: &str) -> T
+  fn parse_value<T>((line_index, line, values): (usize, &str, &str)) -> Result<T, InputError>
   where
     T: FromStr,
-    <T as FromStr>::Err: Debug
   {
-    line.strip_prefix(prefix).unwrap()
-      .trim()
-      .replace(" ", "")
-      .parse().unwrap()
+    values.replace(" ", "").parse()
+      .map_err(|_| InputError::at_token(line_index + 1, line, values.trim_start(), "kerned value is not a number that fits"))
   }
 
-  let allocated_time: TimeUnit = parse_value(lines.get(0).unwrap(), "Time:");
-  let record_distance: DistanceUnit = parse_value(lines.get(1).unwrap(), "Distance:");
+  let [time_line, distance_line] = sheet_lines(text)?;
+  let allocated_time: TimeUnit = parse_value(time_line)?;
+  let record_distance: DistanceUnit = parse_value(distance_line)?;
 
-  Race { allocated_time, record_distance }
+  Ok(Race { allocated_time, record_distance })
 }
 
+/// Holding times that beat the record, None when the race cannot be won. Roots with no whole holding time in
+/// between, such as '(1, 0)', leave no way to win either
 pub fn count_ways_solve_equation(solution: RaceSolution) -> Option<u64> {
-  solution.map(|solution| solution.1 - solution.0 + 1)
+  solution.map(|solution| (solution.1 + 1).saturating_sub(solution.0))
+    .filter(|&ways| ways > 0)
 }
 
-pub fn product_ways_of_winning_race(races: &Vec<Race>) -> Result<u64, &str> {
+/// Product of the ways to win every race, failing with the races that cannot be won
+pub fn product_ways_of_winning_race(races: &[Race]) -> Result<u64, String> {
   // Get solutions to races
-  let race_solutions: Vec<RaceSolution> = races.iter()
-    .map(|race| race.find_way_to_beat_record())
+  let race_ways: Vec<Option<u64>> = races.iter()
+    .map(|race| count_ways_solve_equation(race.find_way_to_beat_record()))
     .collect();
 
   // Check if any failed at being solved
-  let failed_races: Vec<usize> = race_solutions.iter().enumerate()
-    .filter(|&(_, &race_solution)| race_solution.is_none())
-    .map(|(race_number, _)| race_number)
+  let failed_races: Vec<String> = race_ways.iter().enumerate()
+    .filter(|&(_, race_ways)| race_ways.is_none())
+    .map(|(race_number, _)| race_number.to_string())
     .collect();
-  if failed_races.len() > 0 {
-    failed_races.into_iter()
-      .for_each(|race_number| println!("🚨 Race '{}' has no solution!", race_number));
-    return Err("🚨 Problem could not be solved!");
-  }
-
-  // Actually compute solution 
-  let race_solution = race_solutions.into_iter()
-    .map(|solution| count_ways_solve_equation(solution))
-    .map(|number_ways| number_ways.unwrap())
-    .product();
-  Ok(race_solution)
+  if !failed_races.is_empty() {
+    return Err(format!("Race '{}' cannot be won", failed_races.join("', '")));
+  }
+
+  // Actually compute solution
+  Ok(race_ways.into_iter().flatten().product())
 }
 
 // ================================================= IMPLEMENTATIONS =================================================
@@ -110,7 +129,7 @@ impl Race {
   */ 
   pub fn find_way_to_beat_record(&self) -> RaceSolution {
 
-    let solution = find_roots_quadratic(-1 as f64, self.allocated_time as f64, - (self.record_distance as f64));
+    let solution = find_roots_quadratic(-1_f64, self.allocated_time as f64, - (self.record_distance as f64));
     if let Roots::Two(solution) = solution {
       let start_range: f64 = solution[0];
       let end_range: f64 = solution[1];
@@ -130,4 +149,126 @@ impl Race {
 
     None
   }
-}
\ No newline at end of file
+}
+
+impl Solver for Puzzle {
+  type Input = (Vec<Race>, Race);
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    Ok((parse_races(text)?, parse_race_kerning(text)?))
+  }
+
+  fn render(input: &Self::Input) -> String {
+    let times: Vec<String> = input.0.iter().map(|race| race.allocated_time.to_string()).collect();
+    let distances: Vec<String> = input.0.iter().map(|race| race.record_distance.to_string()).collect();
+    format!("Time: {}\nDistance: {}", times.join(" "), distances.join(" "))
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    product_ways_of_winning_race(&input.0).map_or_else(Answer::unsolved, Answer::from)
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    count_ways_solve_equation(input.1.find_way_to_beat_record())
+      .map_or_else(|| Answer::unsolved("The kerned race cannot be won"), Answer::from)
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use aoc_common::random::{differential, Random};
+
+  const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200";
+
+  #[test]
+  fn ways_to_beat_each_race() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    let ways: Vec<Option<u64>> = input.0.iter()
+      .map(|race| count_ways_solve_equation(race.find_way_to_beat_record()))
+      .collect();
+    assert_eq!(ways, vec![Some(4), Some(8), Some(9)]);
+  }
+
+  #[test]
+  fn record_on_an_exact_root_is_not_a_win() {
+    // Holding for 10ms or 20ms ties the record of 200mm, so only 11..=19 beat it
+    let race = Race { allocated_time: 30, record_distance: 200 };
+    assert_eq!(race.find_way_to_beat_record(), Some((11, 19)));
+  }
+
+  #[test]
+  fn race_too_short_to_win() {
+    let race = Race { allocated_time: 1, record_distance: 0 };
+    assert_eq!(count_ways_solve_equation(race.find_way_to_beat_record()), None);
+  }
+
+  #[test]
+  fn unwinnable_races_are_unsolved() {
+    let input = Puzzle::parse("Time: 7 2\nDistance: 9 5").unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "no answer (Race '1' cannot be won)");
+    assert!(matches!(Puzzle::part_two(&Puzzle::parse("Time: 2\nDistance: 5").unwrap()), Answer::Unsolved(_)));
+  }
+
+  #[test]
+  fn malformed_sheets_are_rejected() {
+    let texts = [
+      "Time: 7 15\nDistance: 9",
+      "Time: 7\nDistance: 9\nTime: 7",
+      "Time: 7 15\nDistance 9 40",
+      "Time: 99999999999 99999999999\nDistance: 9 40"];
+    for text in texts {
+      assert!(Puzzle::parse(text).is_err(), "🚨 Sheet should not parse:\n{}", text);
+    }
+  }
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "288");
+  }
+
+  #[test]
+  fn example_part_two() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "71503");
+  }
+
+  /// Races short enough for the concatenated one to be counted one holding time at a time, records included
+  /// that can only be tied
+  fn random_races(random: &mut Random) -> String {
+    let races: Vec<(TimeUnit, DistanceUnit)> = (0..random.range(1, 3))
+      .map(|_| {
+        let allocated_time = random.range(1, 60);
+        (allocated_time, random.below(allocated_time * allocated_time / 4 + 1))
+      })
+      .collect();
+
+    let times: Vec<String> = races.iter().map(|(time, _)| time.to_string()).collect();
+    let distances: Vec<String> = races.iter().map(|(_, distance)| distance.to_string()).collect();
+    format!("Time: {}\nDistance: {}", times.join(" "), distances.join(" "))
+  }
+
+  /// Reference for the quadratic roots: every holding time tried in turn
+  fn naive_ways_to_win(race: &Race) -> u64 {
+    (0..=race.allocated_time)
+      .filter(|&held| held * (race.allocated_time - held) > race.record_distance)
+      .count() as u64
+  }
+
+  /// Ways to win every race of the text, the kerned one last
+  fn ways_to_win(text: &str, count: impl Fn(&Race) -> Option<u64>) -> Vec<Option<u64>> {
+    let (races, kerned) = Puzzle::parse(text).unwrap();
+    races.iter().chain([&kerned]).map(count).collect()
+  }
+
+  #[test]
+  fn quadratic_roots_match_naive_count() {
+    differential(2000, random_races,
+      |text| ways_to_win(text, |race| Some(naive_ways_to_win(race)).filter(|&ways| ways > 0)),
+      |text| ways_to_win(text, |race| count_ways_solve_equation(race.find_way_to_beat_record())));
+  }
+}
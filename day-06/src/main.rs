@@ -1,23 +1,23 @@
-// Load Local Modules
-mod read;
-
 // Imports
-use day_06::parse_races;
-use day_06::product_ways_of_winning_race;
-use day_06::count_ways_solve_equation;
-use day_06::parse_race_kerning;
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_06::Puzzle;
 
 fn main() {
 
-    let input = read::read_lines("input.txt".to_owned());
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let races = parse_races(&input);
-    let ways_of_winning = product_ways_of_winning_race(&races).unwrap();
+    let ways_of_winning = Puzzle::part_one(&input);
     println!("\r🚘 Ways of winning the competition: '{}' (Part 1)", ways_of_winning);
 
     // Part 2
-    let race_kerning = parse_race_kerning(&input);
-    let ways_of_winning_kerning = count_ways_solve_equation(race_kerning.find_way_to_beat_record()).unwrap() ;
+    let ways_of_winning_kerning = Puzzle::part_two(&input);
     println!("\r🚘 Ways of winning the race: '{}' (Part 2)", ways_of_winning_kerning);
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use aoc_common::error::InputError;
+use aoc_common::solver::{Answer, Solver};
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 
@@ -42,44 +44,52 @@ pub struct GameEntry {
   bid: BidUnit,
 }
 
-type Game = Vec<GameEntry>;
+pub type Game = Vec<GameEntry>;
+
+pub struct Puzzle;
+
+// ==================================================== CONSTANTS ====================================================
+const HAND_SIZE: usize = 5;
+const CARDS: [(char, Card); 13] = [
+  ('2', Card::Card2), ('3', Card::Card3), ('4', Card::Card4), ('5', Card::Card5), ('6', Card::Card6),
+  ('7', Card::Card7), ('8', Card::Card8), ('9', Card::Card9), ('T', Card::CardT), ('J', Card::CardJ),
+  ('Q', Card::CardQ), ('K', Card::CardK), ('A', Card::CardA)];
 
 // =============================================== AUXILIARY FUNCTIONS ===============================================
-fn parse_card(card_id: char) -> Card {
-  match card_id {
-    '2' => Card::Card2,
-    '3' => Card::Card3,
-    '4' => Card::Card4,
-    '5' => Card::Card5,
-    '6' => Card::Card6,
-    '7' => Card::Card7,
-    '8' => Card::Card8,
-    '9' => Card::Card9,
-    'T' => Card::CardT,
-    'J' => Card::CardJ,
-    'Q' => Card::CardQ,
-    'K' => Card::CardK,
-    'A' => Card::CardA,
-    symbol => panic!("🚨 Symbol '{}' not recognized as a valid card", symbol)
-  }
+fn parse_card(card_id: char) -> Option<Card> {
+  CARDS.iter()
+    .find(|&&(symbol, _)| symbol == card_id)
+    .map(|&(_, card)| card)
+}
+
+fn card_symbol(card: Card) -> char {
+  CARDS.iter()
+    .find(|&&(_, other)| other == card)
+    .map(|&(symbol, _)| symbol)
+    .unwrap()
 }
 
-fn parse_game_entry(line: &String) -> GameEntry {
+fn parse_game_entry(line_index: usize, line: &str) -> Result<GameEntry, InputError> {
+  let error = |token: &str, reason: &str| InputError::at_token(line_index + 1, line, token, reason);
   let split: Vec<&str> = line.split_whitespace().collect();
+  let [hand_cards, bid_value] = split[..] else {
+    return Err(error(line, "line is not formatted as 'hand bid'"));
+  };
 
-  let hand = split.get(0).unwrap().to_owned()
-    .chars().into_iter()
+  let hand: Hand = hand_cards.chars()
     .map(parse_card)
-    .collect();
-  let bid = split.get(1).unwrap().to_owned()
-    .parse().unwrap();
-    
-  GameEntry { hand, bid }
+    .collect::<Option<_>>()
+    .filter(|hand: &Hand| hand.len() == HAND_SIZE)
+    .ok_or_else(|| error(hand_cards, "hand is not five cards out of '23456789TJQKA'"))?;
+  let bid = bid_value.parse()
+    .map_err(|_| error(bid_value, "bid is not a number"))?;
+
+  Ok(GameEntry { hand, bid })
 }
 
-pub fn parse_game_entries(lines: &Vec<String>) -> Game {
-  lines.iter()
-    .map(|line| parse_game_entry(line))
+pub fn parse_game_entries(text: &str) -> Result<Game, InputError> {
+  text.lines().enumerate()
+    .map(|(line_index, line)| parse_game_entry(line_index, line))
     .collect()
 }
 
@@ -116,7 +126,7 @@ fn derive_hand_type(hand: &Hand, joker: bool) -> HandType {
 
 pub fn compute_winnings(game: &Game, joker: bool) -> BidUnit {
   // Sort game entries
-  let mut game_entries: Vec<&GameEntry> = game.into_iter().collect();
+  let mut game_entries: Vec<&GameEntry> = game.iter().collect();
   game_entries.sort_by(|&entry1, &entry2| GameEntry::custom_cmp(entry1, entry2, joker));
   // Get winnings
   game_entries.into_iter().enumerate()
@@ -159,3 +169,71 @@ impl GameEntry {
       }
   }
 }
+
+impl Solver for Puzzle {
+  type Input = Game;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    parse_game_entries(text)
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.iter()
+      .map(|entry| format!("{} {}", entry.hand.iter().map(|&card| card_symbol(card)).collect::<String>(), entry.bid))
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    compute_winnings(input, false).into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    compute_winnings(input, true).into()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+  fn hand(cards: &str) -> Hand {
+    cards.chars().map(|card| parse_card(card).unwrap()).collect()
+  }
+
+  #[test]
+  fn malformed_hands_are_rejected() {
+    for text in ["32T3 765", "32T3KK 765", "32T1K 765", "32T3K", "32T3K 765 1"] {
+      assert!(Puzzle::parse(text).is_err(), "🚨 Hand should not parse: {}", text);
+    }
+  }
+
+  #[test]
+  fn hand_types() {
+    assert!(derive_hand_type(&hand("32T3K"), false) == HandType::OnePair);
+    assert!(derive_hand_type(&hand("KK677"), false) == HandType::TwoPair);
+    assert!(derive_hand_type(&hand("T55J5"), false) == HandType::ThreeOfKind);
+    assert!(derive_hand_type(&hand("T55J5"), true) == HandType::FourOfKind);
+    assert!(derive_hand_type(&hand("JJJJJ"), true) == HandType::FiveOfKind);
+  }
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "6440");
+  }
+
+  #[test]
+  fn example_part_two() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "5905");
+  }
+}
@@ -1,20 +1,23 @@
-// Load Local Modules
-mod read;
-
 // Imports
-use day_07::parse_game_entries;
-use day_07::compute_winnings;
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_07::Puzzle;
 
 fn main() {
 
-    let input = read::read_lines("input.txt".to_owned());
-    let game = parse_game_entries(&input);
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let winnings = compute_winnings(&game, false);
+    let winnings = Puzzle::part_one(&input);
     println!("\r💰 Game Winnings: '{}' (Part 1)", winnings);
 
     // Part 2
-    let winnings_joker = compute_winnings(&game, true);
+    let winnings_joker = Puzzle::part_two(&input);
     println!("\r💰 Game Winnings (with joker): '{}' (Part 2)", winnings_joker);
 }
\ No newline at end of file
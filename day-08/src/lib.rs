@@ -1,6 +1,9 @@
 use std::collections::{HashMap, HashSet};
-use competitive_programming_rs::math::chinese_remainder_theorem::chinese_remainder_theorem;
+use aoc_common::graph::{Graph, NodeIndex};
+use aoc_common::math::chinese_remainder_theorem;
 use regex::Regex;
+use aoc_common::error::InputError;
+use aoc_common::solver::{Answer, Solver};
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 type NodeItem = char;
@@ -14,63 +17,81 @@ pub enum Direction {
 
 pub struct Map {
   instructions: Vec<Direction>,
-  nodes: Vec<Node>,
-  edges_map: HashMap<usize, HashMap<Direction, usize>>,
+  graph: Graph<Node, Direction>,
+  declared: Vec<NodeIndex>,
 }
 
+pub struct Puzzle;
+
 // =============================================== AUXILIARY FUNCTIONS ===============================================
-fn parse_instruction(char_direction: char) -> Direction {
+fn parse_instruction(char_direction: char) -> Option<Direction> {
   match char_direction {
-    'L' => Direction::Left,
-    'R' => Direction::Right,
-    other => panic!("🚨 Characther '{}' not recognized as a valid direction.", other)
+    'L' => Some(Direction::Left),
+    'R' => Some(Direction::Right),
+    _ => None,
   }
 }
 
 fn parse_node(code: &str) -> Node {
-  let mut chars: Vec<char> = code.chars().collect();
-  (chars.remove(0), chars.remove(0), chars.remove(0))
+  let mut chars = code.chars();
+  (chars.next().unwrap(), chars.next().unwrap(), chars.next().unwrap())
+}
+
+fn node_code(node: &Node) -> String {
+  [node.0, node.1, node.2].iter().collect()
 }
 
-pub fn parse_map(mut lines: Vec<String>) -> Map {
+pub fn parse_map(text: &str) -> Result<Map, InputError> {
+  let mut lines = text.lines().enumerate();
   // Parse first line with list of instructions
-  let instructions: Vec<Direction> = lines.remove(0)
-    .chars().into_iter()
+  let instructions_line = lines.next().map_or("", |(_, line)| line);
+  let instructions: Vec<Direction> = instructions_line
+    .chars()
     .map(parse_instruction)
-    .collect();
+    .collect::<Option<_>>()
+    .filter(|instructions: &Vec<Direction>| !instructions.is_empty())
+    .ok_or_else(|| {
+      let column_index = instructions_line.chars()
+        .position(|direction| parse_instruction(direction).is_none())
+        .unwrap_or(0);
+      InputError::parse(1, column_index + 1, instructions_line, "instructions are not a list of 'L' and 'R'")
+    })?;
 
   // Parse empty line
-  lines.remove(0);
+  if let Some((line_index, line)) = lines.next().filter(|(_, line)| !line.is_empty()) {
+    return Err(InputError::parse(line_index + 1, 1, line, "instructions are not followed by an empty line"));
+  }
 
-  let mut nodes: Vec<Node> = Vec::new();
-  let mut edges_map: HashMap<usize, HashMap<Direction, usize>> = HashMap::new();
+  let mut graph: Graph<Node, Direction> = Graph::new();
+  let mut declared: Vec<NodeIndex> = Vec::new();
+  let mut destinations: Vec<(usize, &str, &str, NodeIndex)> = Vec::new();
   // Iterate remaining lines and parse them using Regex
   let regex_expression = Regex::new(r"^([A-Z0-9]{3}) = \(([A-Z0-9]{3}), ([A-Z0-9]{3})\)$").unwrap();
-  lines.into_iter()
-    .for_each(|line| {
-
-      let regex_captures = regex_expression.captures(&line).unwrap();
-
-      let source  = parse_node(regex_captures.get(1).unwrap().as_str());
-      let destination_left = parse_node(regex_captures.get(2).unwrap().as_str());
-      let destination_right  = parse_node(regex_captures.get(3).unwrap().as_str());
-
-      if !nodes.contains(&source) { nodes.push(source) }
-      if !nodes.contains(&destination_left) { nodes.push(destination_left) }
-      if !nodes.contains(&destination_right) { nodes.push(destination_right) }
-
-      let source_index = nodes.iter().position(|&elem| elem == source).unwrap();
-      let destinaton_left_index = nodes.iter().position(|&elem| elem == destination_left).unwrap();
-      let destination_right_index = nodes.iter().position(|&elem| elem == destination_right).unwrap();
-
-      let mut directions_map: HashMap<Direction, usize> = HashMap::new();
-      directions_map.insert(Direction::Left, destinaton_left_index);
-      directions_map.insert(Direction::Right, destination_right_index);
+  for (line_index, line) in lines {
+    let regex_captures = regex_expression.captures(line)
+      .ok_or_else(|| InputError::parse(line_index + 1, 1, line, "line is not formatted as 'AAA = (BBB, CCC)'"))?;
+    let [source, left, right] = [1, 2, 3].map(|group| regex_captures.get(group).map_or(line, |capture| capture.as_str()));
+
+    let source_index = graph.add_node(parse_node(source));
+    if declared.contains(&source_index) {
+      return Err(InputError::at_token(line_index + 1, line, source, "node is declared twice"));
+    }
+    let destination_left_index = graph.add_node(parse_node(left));
+    let destination_right_index = graph.add_node(parse_node(right));
+
+    graph.add_edge(source_index, Direction::Left, destination_left_index);
+    graph.add_edge(source_index, Direction::Right, destination_right_index);
+    declared.push(source_index);
+    destinations.push((line_index, line, left, destination_left_index));
+    destinations.push((line_index, line, right, destination_right_index));
+  }
 
-      edges_map.insert(source_index, directions_map);
-    });
+  // Every node reached must lead somewhere in turn
+  if let Some(&(line_index, line, node, _)) = destinations.iter().find(|(_, _, _, index)| !declared.contains(index)) {
+    return Err(InputError::at_token(line_index + 1, line, node, "destination node is never declared"));
+  }
 
-  Map { instructions, nodes, edges_map }
+  Ok(Map { instructions, graph, declared })
 }
 
 // ================================================= IMPLEMENTATIONS =================================================
@@ -84,9 +105,8 @@ impl Map {
     let mut chosen_neighbours = Vec::new();
     current_nodes.iter()
       .for_each(|&from_node| {
-        let neighbours = self.edges_map.get(&from_node.0).unwrap();
-        let neighbour_selected_index = neighbours.get(current_instruction).unwrap().to_owned();
-        let neighbour_selected = self.nodes.get(neighbour_selected_index).unwrap().to_owned();
+        let neighbour_selected_index = self.graph.follow(from_node.0, current_instruction).unwrap();
+        let neighbour_selected = self.graph.node(neighbour_selected_index).to_owned();
 
         chosen_neighbours.push((neighbour_selected_index, neighbour_selected));
       });
@@ -94,10 +114,13 @@ impl Map {
     chosen_neighbours
   }
 
-  pub fn follow_instructions(&self, start_node: Node, end_node: Node) -> usize {
+  /// Steps from the start node to the end node, None when either is missing or the end is never reached. A
+  /// walk still going after every node was visited on every instruction goes round in circles
+  pub fn follow_instructions(&self, start_node: Node, end_node: Node) -> Option<usize> {
 
-    let start_node_index = self.nodes.iter().position(|&elem| elem == start_node).unwrap();
-    let end_node_index = self.nodes.iter().position(|&elem| elem == end_node).unwrap();
+    let start_node_index = self.graph.index_of(&start_node)?;
+    let end_node_index = self.graph.index_of(&end_node)?;
+    let max_steps = self.graph.len() * self.instructions.len();
 
     let mut finished_path: bool = false;
     let mut current_nodes: Vec<(usize, Node)> = vec![(start_node_index, start_node)];
@@ -106,49 +129,58 @@ impl Map {
     let mut steps: usize = 0;
 
     while !finished_path {
+      if steps >= max_steps {
+        return None;
+      }
 
       let neighbours = self.make_iteration(current_nodes, steps);
 
       // Make iteration
-      steps = steps + 1;
+      steps += 1;
       current_nodes = neighbours;
       // Verify to finish cycle
       finished_path = current_nodes.first().unwrap().0 == end_node_index;
     }
 
-    return steps;
+    Some(steps)
   }
 
-  pub fn follow_ghostly_instructions(&self, start_node_item: NodeItem, end_node_item: NodeItem) -> usize {
+  /// Steps until every ghost stands on an end node, None when there is no ghost or one of them does not come
+  /// back to a single end node within two rounds of every node on every instruction
+  pub fn follow_ghostly_instructions(&self, start_node_item: NodeItem, end_node_item: NodeItem) -> Option<usize> {
 
     fn check_node_item_id(node: Node, item_id: NodeItem) -> bool { node.2 == item_id }
 
     let mut finished_path: bool = false;
-    let mut current_nodes: Vec<(usize, Node)> = self.nodes.iter().enumerate()
+    let mut current_nodes: Vec<(usize, Node)> = self.graph.nodes()
       .filter(|&(_, node)| check_node_item_id(*node, start_node_item))
       .map(|(node_index, &node)| (node_index, node))
       .collect();
     let mut track_final_states: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    let max_steps = 2 * self.graph.len() * self.instructions.len();
+    if current_nodes.is_empty() {
+      return None;
+    }
 
     // Initialize structure for path
     let mut steps: usize = 0;
 
     while !finished_path {
-      
+      if steps >= max_steps {
+        return None;
+      }
+
       let neighbours = self.make_iteration(current_nodes, steps);
       
       // Make iteration
-      steps = steps + 1;
+      steps += 1;
       current_nodes = neighbours;
       // Update track final states
       current_nodes.iter().enumerate()
         .filter(|&(_, &(_, node))| check_node_item_id(node, end_node_item))
         .for_each(|(ghost_index, &(node_index, _))| {
           let key = (ghost_index, node_index);
-          if !track_final_states.contains_key(&key) { track_final_states.insert(key, Vec::new()); }
-
-          let current_list = track_final_states.get_mut(&key).unwrap();
-          current_list.push(steps);
+          track_final_states.entry(key).or_default().push(steps);
         });
       
       // Verify to finish cycle
@@ -162,8 +194,8 @@ impl Map {
       finished_path =all_ghosts_identified && only_x_ghost_identified && all_ghost_two_iterations;
     }
 
-    let equation_parameters: Vec<(i64, i64)> = track_final_states.into_iter()
-      .map(|(_, mut iterations)| {
+    let equation_parameters: Vec<(i64, i64)> = track_final_states.into_values()
+      .map(|mut iterations| {
         let first_occurrence = iterations.remove(0) as i64;
         let second_occurrence = iterations.remove(0) as i64;
         (second_occurrence - first_occurrence, first_occurrence)})
@@ -171,8 +203,120 @@ impl Map {
 
     let constants: Vec<i64> = equation_parameters.iter().map(|&(_, constant)| constant).collect();
     let coefficient: Vec<i64> = equation_parameters.iter().map(|&(coefficient, _)| coefficient).collect();
-    let solution = chinese_remainder_theorem(&constants, &coefficient);
+    let solution = chinese_remainder_theorem(&constants, &coefficient)?;
+
+    Some(solution.1 as usize)
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = Map;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    parse_map(text)
+  }
+
+  fn render(input: &Self::Input) -> String {
+    let instructions: String = input.instructions.iter()
+      .map(|direction| match direction { Direction::Left => 'L', Direction::Right => 'R' })
+      .collect();
+    let nodes = input.declared.iter()
+      .map(|&node_index| {
+        let code = |direction| node_code(input.graph.node(input.graph.follow(node_index, &direction).unwrap()));
+        format!("{} = ({}, {})",
+          node_code(input.graph.node(node_index)), code(Direction::Left), code(Direction::Right))
+      });
+
+    [instructions, String::new()].into_iter()
+      .chain(nodes)
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
 
-    return solution.unwrap().1 as usize;
+  fn part_one(input: &Self::Input) -> Answer {
+    input.follow_instructions(('A', 'A', 'A'), ('Z', 'Z', 'Z'))
+      .map_or_else(|| Answer::unsolved("There is no way from 'AAA' to 'ZZZ'"), Answer::from)
   }
-}
\ No newline at end of file
+
+  fn part_two(input: &Self::Input) -> Answer {
+    input.follow_ghostly_instructions('A', 'Z')
+      .map_or_else(|| Answer::unsolved("The ghosts never stand on end nodes together"), Answer::from)
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+  const EXAMPLE_REPEATED: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+  const EXAMPLE_GHOSTS: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+  #[test]
+  fn malformed_maps_are_rejected() {
+    let texts = [
+      "RXL\n\nAAA = (AAA, AAA)",
+      "RL\nAAA = (AAA, AAA)",
+      "RL\n\nAAA = (AAA, ZZZ)",
+      "RL\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)",
+      "RL\n\nAAA = AAA, AAA"];
+    for text in texts {
+      assert!(Puzzle::parse(text).is_err(), "🚨 Map should not parse:\n{}", text);
+    }
+  }
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "2");
+  }
+
+  #[test]
+  fn example_part_one_repeating_instructions() {
+    let input = Puzzle::parse(EXAMPLE_REPEATED).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "6");
+  }
+
+  #[test]
+  fn example_part_two() {
+    let input = Puzzle::parse(EXAMPLE_GHOSTS).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "6");
+  }
+
+  #[test]
+  fn unreachable_ends_are_unsolved() {
+    let input = Puzzle::parse("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+    assert!(matches!(Puzzle::part_one(&input), Answer::Unsolved(_)));
+    assert!(matches!(Puzzle::part_two(&input), Answer::Unsolved(_)));
+    let input = Puzzle::parse("L\n\nBBB = (BBB, BBB)").unwrap();
+    assert!(matches!(Puzzle::part_one(&input), Answer::Unsolved(_)));
+    assert!(matches!(Puzzle::part_two(&input), Answer::Unsolved(_)));
+  }
+}
@@ -1,19 +1,23 @@
-// Load Local Modules
-mod read;
-
 // Imports
-use day_08::parse_map;
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_08::Puzzle;
 
 fn main() {
 
-    let input = read::read_lines("input.txt".to_owned());
-    let map = parse_map(input);
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let computed_steps = map.follow_instructions(('A', 'A', 'A'), ('Z', 'Z', 'Z'));
+    let computed_steps = Puzzle::part_one(&input);
     println!("\r🏜️  Instructed path steps: '{}' (Part 1)", computed_steps);
 
     // Part 2
-    let computed_ghostly_steps = map.follow_ghostly_instructions('A', 'Z');
+    let computed_ghostly_steps = Puzzle::part_two(&input);
     println!("\r🏜️  Instructed ghostly path steps: '{}' (Part 2)", computed_ghostly_steps);
 }
\ No newline at end of file
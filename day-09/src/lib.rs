@@ -1,50 +1,81 @@
+use aoc_common::error::InputError;
+use aoc_common::read;
+use aoc_common::solver::{Answer, Solver};
+pub use aoc_common::math::{ValueReading, ValueVariation};
+pub use aoc_common::math::{checked_variations_steps, compute_variations_steps, estimate_next_value, estimate_prev_value};
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
-pub type ValueReading = i64;
-pub type ValueVariation = Vec<ValueReading>;
 
-// =============================================== AUXILIARY FUNCTIONS ===============================================
-pub fn compute_variations_steps(variation: ValueVariation) -> Vec<ValueVariation> {
-  
-  let mut finished_computing = false;
-  let mut steps_variations: Vec<ValueVariation> = vec![variation];
-  while !finished_computing {
+pub struct Puzzle;
 
-    // Compute next step
-    let last_variation = steps_variations.last().unwrap();
-    let new_step: ValueVariation = last_variation.iter().zip(last_variation.iter().skip(1))
-      .map(|(&prev, &next)| next - prev)
-      .collect();
+// ================================================= IMPLEMENTATIONS =================================================
 
-    // Check if it should stop iteration
-    finished_computing = new_step.iter().all(|&value| value == 0);
+impl Solver for Puzzle {
+  type Input = Vec<Vec<ValueVariation>>;
 
-    // Update general tracker
-    steps_variations.push(new_step);
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    let readings = read::parse_list_int_lines(text, " ")?;
+    readings.into_iter().zip(text.lines()).enumerate()
+      .map(|(line_index, (reading, line))| checked_variations_steps(reading)
+        .ok_or_else(|| InputError::parse(line_index + 1, 1, line, "differences between readings do not fit")))
+      .collect()
   }
 
-  steps_variations
-}
+  fn render(input: &Self::Input) -> String {
+    input.iter()
+      .map(|steps| steps[0].iter().map(|value| value.to_string()).collect::<Vec<String>>().join(" "))
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    input.iter().map(|steps| estimate_next_value(steps)).sum::<ValueReading>().into()
+  }
 
-pub fn estimate_next_value(variation_steps: &Vec<ValueVariation>) -> ValueReading {
-  variation_steps.into_iter()
-    .map(|step| step.last().unwrap().to_owned())
-    .sum()
+  fn part_two(input: &Self::Input) -> Answer {
+    input.iter().map(|steps| estimate_prev_value(steps)).sum::<ValueReading>().into()
+  }
 }
 
-pub fn estimate_prev_value(variation_steps: &Vec<ValueVariation>) -> ValueReading {
-  fn compute_signal(index: usize) -> i64 {
-    match index % 2 {
-      0 => 1,
-      1 => -1,
-      _ => panic!("🚨 Impossible for it to happen!")
-    }
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+  #[test]
+  fn variations_end_on_all_zeroes() {
+    let steps = compute_variations_steps(vec![1, 3, 6, 10, 15, 21]);
+    assert_eq!(steps.last().unwrap(), &vec![0, 0, 0]);
+    assert_eq!(estimate_next_value(&steps), 28);
+    assert_eq!(estimate_prev_value(&steps), 0);
   }
 
-  variation_steps.into_iter().enumerate()
-    .map(|(index, step)| (compute_signal(index), step.first().unwrap().to_owned()))
-    .map(|(signal, step)| signal * step)
-    .sum()
-}
+  #[test]
+  fn short_histories_are_extrapolated() {
+    let input = Puzzle::parse("5\n1 2").unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "8");
+    assert_eq!(Puzzle::part_two(&input).to_string(), "5");
+  }
 
-// ================================================= IMPLEMENTATIONS =================================================
+  #[test]
+  fn overflowing_readings_are_rejected() {
+    assert!(Puzzle::parse("0 3 6\n-9223372036854775808 9223372036854775807").is_err());
+  }
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "114");
+  }
+
+  #[test]
+  fn example_part_two() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "2");
+  }
+}
@@ -1,23 +1,23 @@
-// Load Local Modules
-mod read;
-
 // Imports
-use day_09::ValueReading;
-use day_09::ValueVariation;
-use day_09::compute_variations_steps;
-use day_09::estimate_next_value;
-use day_09::estimate_prev_value;
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_09::Puzzle;
 
 fn main() {
 
-    let input = read::read_list_int_lines("input.txt".to_owned(), " ");
-    let variation_steps: Vec<Vec<ValueVariation>> = input.into_iter().map(compute_variations_steps).collect();
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let sum_estimated_next_values: ValueReading = variation_steps.iter().map(estimate_next_value).sum();
+    let sum_estimated_next_values = Puzzle::part_one(&input);
     println!("\r🧮 Sum of values for next estimations: '{}' (Part 1)", sum_estimated_next_values);
 
     // Part 2
-    let sum_estimated_prev_values: ValueReading = variation_steps.iter().map(estimate_prev_value).sum();
+    let sum_estimated_prev_values = Puzzle::part_two(&input);
     println!("\r🧮 Sum of values for previous estimations: '{}' (Part 2)", sum_estimated_prev_values);
 }
\ No newline at end of file
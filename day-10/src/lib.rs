@@ -1,17 +1,12 @@
 use std::collections::{HashMap, HashSet};
 use strum::IntoEnumIterator;
 use strum_macros::{EnumIter, Display};
+use aoc_common::error::InputError;
+use aoc_common::grid::{Coordinate, CoordinateUnit, Grid};
+use aoc_common::solver::{Answer, Solver};
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
-type CoordinateUnit = i16;
-
-#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug, PartialOrd, Ord)]
-pub struct Coordinate {
-  x: CoordinateUnit,
-  y: CoordinateUnit
-}
-
-#[derive(Clone, Copy, EnumIter, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, EnumIter, Debug)]
 pub enum PipeType {
   Unknown,
   NorthSouth,
@@ -36,7 +31,7 @@ struct PipeMapSolution {
 
 pub struct PipeMap {
   start: Coordinate,
-  map: HashMap<Coordinate, PipeType>,
+  map: Grid<PipeType>,
   solution: Option<PipeMapSolution>
 }
 
@@ -48,38 +43,21 @@ enum Direction {
   North
 }
 
+pub struct Puzzle;
+
 // =================================================== CONSTANTS  ===================================================
-const COORDINATE_DELTA_NORTH: Coordinate  = Coordinate { x:  0, y: -1 };
-const COORDINATE_DELTA_SOUTH: Coordinate  = Coordinate { x:  0, y:  1 };
-const COORDINATE_DELTA_EAST: Coordinate   = Coordinate { x:  1, y:  0 };
-const COORDINATE_DELTA_WEST: Coordinate   = Coordinate { x: -1, y:  0 };
+const COORDINATE_DELTA_NORTH: Coordinate  = Coordinate::NORTH;
+const COORDINATE_DELTA_SOUTH: Coordinate  = Coordinate::SOUTH;
+const COORDINATE_DELTA_EAST: Coordinate   = Coordinate::EAST;
+const COORDINATE_DELTA_WEST: Coordinate   = Coordinate::WEST;
+const PIPE_SYMBOLS: [(char, PipeType); 8] = [
+  ('S', PipeType::Unknown), ('|', PipeType::NorthSouth), ('-', PipeType::EastWest),
+  ('L', PipeType::SquareNorthEast), ('J', PipeType::SquareNorthWest), ('F', PipeType::SquareSouthEast),
+  ('7', PipeType::SquareSouthWest), ('.', PipeType::EmptyGround)];
 
 // =============================================== AUXILIARY FUNCTIONS ===============================================
 
 // ================================================= IMPLEMENTATIONS =================================================
-impl Coordinate {
-  fn new(x: CoordinateUnit, y: CoordinateUnit) -> Coordinate {
-    Coordinate { x, y }
-  }
-}
-
-impl std::ops::Add for Coordinate {
-  type Output = Coordinate;
-
-  fn add(self, other: Coordinate) -> Coordinate {
-    let new_x: CoordinateUnit = self.x + other.x;
-    let new_y: CoordinateUnit = self.y + other.y;
-    
-    Coordinate::new(new_x, new_y)
-  }
-}
-
-impl std::fmt::Display for Coordinate {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-      write!(f, "({}, {})", self.x, self.y)
-    }
-}
-
 impl PipeType {
   fn valid_deltas_pipe(&self) -> Vec<Coordinate> {
     match self {
@@ -95,35 +73,33 @@ impl PipeType {
   }
 }
 
-impl From<char> for PipeType {
-  fn from(value: char) -> Self {
-    match value {
-      'S' => PipeType::Unknown,
-      '|' => PipeType::NorthSouth,
-      '-' => PipeType::EastWest,
-      'L' => PipeType::SquareNorthEast,
-      'J' => PipeType::SquareNorthWest,
-      'F' => PipeType::SquareSouthEast,
-      '7' => PipeType::SquareSouthWest,
-      '.' => PipeType::EmptyGround,
-      unknown_char => panic!("🚨 Char '{}' was not recognized as a valid type", unknown_char)
-    }
+impl PipeType {
+  fn parse(value: char) -> Option<Self> {
+    PIPE_SYMBOLS.iter()
+      .find(|&&(symbol, _)| symbol == value)
+      .map(|&(_, pipe_type)| pipe_type)
+  }
+}
+
+impl From<PipeType> for char {
+  fn from(value: PipeType) -> Self {
+    PIPE_SYMBOLS.iter()
+      .find(|&&(_, pipe_type)| pipe_type == value)
+      .map(|&(symbol, _)| symbol)
+      .unwrap()
   }
 }
 
 impl PipeMap {
-  pub fn new(map_unparsed: Vec<Vec<char>>) -> Self {
-    let map: HashMap<Coordinate, PipeType> = map_unparsed.into_iter().enumerate()
-      .flat_map(|(row_index, row_chars)|
-        row_chars.into_iter().enumerate()
-          .map(move |(column_index, char)|
-            (Coordinate::new(column_index as CoordinateUnit, row_index as CoordinateUnit), PipeType::from(char))))
-      .collect();
-    let start = map.iter()
-      .filter(|&(_, &pipe_type)| matches!(pipe_type, PipeType::Unknown))
-      .last().unwrap().0.to_owned();
+  pub fn new(text: &str) -> Result<Self, InputError> {
+    let map: Grid<PipeType> = Grid::parse_text(text, PipeType::parse)?;
+    let starts: Vec<Coordinate> = map.positions(|&pipe_type| matches!(pipe_type, PipeType::Unknown)).collect();
+    let &[start] = &starts[..] else {
+      let (line, column) = starts.get(1).map_or((1, 1), |second| (second.y as usize + 1, second.x as usize + 1));
+      return Err(InputError::parse(line, column, "S", "map does not have exactly one start"));
+    };
 
-    PipeMap { start, map, solution: None }
+    Ok(PipeMap { start, map, solution: None })
   }
 
   fn get_movable_positions(&self, from_position: Coordinate) -> Vec<Coordinate> {
@@ -143,19 +119,21 @@ impl PipeMap {
       .collect()
   }
 
-  fn compute_pipe_from_prev(&self, target_spot: Coordinate, prev_spots: HashSet<Coordinate>) -> PipeType {
+  fn compute_pipe_from_prev(&self, target_spot: Coordinate, prev_spots: HashSet<Coordinate>) -> Option<PipeType> {
     PipeType::iter()
       .map(|pipe_type| (
         pipe_type,
         pipe_type.valid_deltas_pipe().into_iter()
           .map(|delta| target_spot + delta)
           .collect::<HashSet<Coordinate>>()))
-      .find(|(_, neighbours)| prev_spots.is_subset(&neighbours) && neighbours.is_subset(&prev_spots))
+      .find(|(_, neighbours)| prev_spots.is_subset(neighbours) && neighbours.is_subset(&prev_spots))
       .map(|(pipe_type, _)| pipe_type)
-      .unwrap() 
   }
 
-  pub fn compute_solution(&mut self) {
+  /// Fails when the start is not on a loop, or when more than the two pipes of the loop connect to it
+  pub fn compute_solution(&mut self) -> Result<(), InputError> {
+    let start_error = || InputError::parse(self.start.y as usize + 1, self.start.x as usize + 1, "S",
+      "start is not on a loop with exactly two pipes connected to it");
     // Initialize Distance Map
     let mut distance_map: HashMap<Coordinate, Distance> = HashMap::new();
     distance_map.insert(self.start, Distance { distance: 0, prev: vec![] });
@@ -170,7 +148,8 @@ impl PipeMap {
       let (picked_key, picked_distance) = distance_map.iter()
         .filter(|&(coordinate, _)| !moved_coordintates.contains(coordinate))
         .min_by_key(|&(_, distance)| distance.distance)
-        .map(|(key, value)| (key.to_owned(), value.to_owned())).unwrap();
+        .map(|(key, value)| (key.to_owned(), value.to_owned()))
+        .ok_or_else(start_error)?;
       let picked_new_positions: Vec<Coordinate> = self.get_movable_positions(picked_key).into_iter()
         .filter(|position| !moved_coordintates.contains(position))
         .collect();
@@ -202,8 +181,9 @@ impl PipeMap {
       .filter(|&(_, distance)| distance.prev.contains(&self.start))
       .map(|(coordinate, _)| coordinate.to_owned())
       .collect();
-    let start_pipe_type = self.compute_pipe_from_prev(self.start, prev_to_start);
-    self.map.insert(self.start, start_pipe_type);
+    let start_pipe_type = self.compute_pipe_from_prev(self.start, prev_to_start)
+      .ok_or_else(start_error)?;
+    self.map[self.start] = start_pipe_type;
 
     self.solution = Some(
       PipeMapSolution {
@@ -211,6 +191,7 @@ impl PipeMap {
         distance_map
       }
     );
+    Ok(())
   }
 
   pub fn find_loop(&self) -> HashSet<Coordinate> {
@@ -221,8 +202,7 @@ impl PipeMap {
     let mut found_loop: HashSet<Coordinate> = HashSet::new();
 
     // Iterate until nothing left to process
-    while !to_process.is_empty() {
-      let picked_position = to_process.pop().unwrap();
+    while let Some(picked_position) = to_process.pop() {
       let picked_distance = solution.distance_map.get(&picked_position).unwrap();
       // Update structs
       picked_distance.prev.iter().for_each(|prev| to_process.push(prev.to_owned()));
@@ -275,7 +255,7 @@ impl PipeMap {
 
     let mut current_position = position.to_owned();
     let mut positions: HashSet<Coordinate> = HashSet::new();
-    while self.map.contains_key(&current_position) && !loop_found.contains(&current_position) {
+    while self.map.contains(&current_position) && !loop_found.contains(&current_position) {
       positions.insert(current_position.to_owned());
       current_position = current_position + direction_delta;
     }
@@ -335,4 +315,131 @@ impl PipeMap {
     empty_positions
   }
 
-}
\ No newline at end of file
+}
+
+impl Solver for Puzzle {
+  type Input = PipeMap;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    let mut map = PipeMap::new(text)?;
+    map.compute_solution()?;
+    Ok(map)
+  }
+
+  fn render(input: &Self::Input) -> String {
+    let mut map = input.map.clone();
+    map[input.start] = PipeType::Unknown;
+    map.to_string().trim_end_matches('\n').to_owned()
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    let map_loop = input.find_loop();
+    input.find_loop_distances(&map_loop).into_iter()
+      .map(|(_, distance)| distance)
+      .max().unwrap()
+      .into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    let map_loop = input.find_loop();
+    input.find_inside_spots(&map_loop).len().into()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE_SIMPLE: &str = "\
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+
+  const EXAMPLE_COMPLEX: &str = "\
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+
+  const EXAMPLE_ENCLOSED: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+  const EXAMPLE_SQUEEZED: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+  const EXAMPLE_JUNK: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+  #[test]
+  fn maps_without_a_single_loop_are_rejected() {
+    let texts = [
+      "-L|F7\n7.-7|\nL|7||",
+      "S-7\n|.|\nL-S",
+      "S-7\n|.|\nL-.",
+      "-S-7\n.|.|\n.L-J",
+      "-L|F7\n7S-7|\nL|7|"];
+    for text in texts {
+      assert!(Puzzle::parse(text).is_err(), "🚨 Map should not parse:\n{}", text);
+    }
+  }
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE_SIMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "4");
+  }
+
+  #[test]
+  fn example_part_one_complex_loop() {
+    let input = Puzzle::parse(EXAMPLE_COMPLEX).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "8");
+  }
+
+  #[test]
+  fn example_part_two() {
+    let input = Puzzle::parse(EXAMPLE_ENCLOSED).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "4");
+  }
+
+  #[test]
+  fn example_part_two_squeezing_between_pipes() {
+    let input = Puzzle::parse(EXAMPLE_SQUEEZED).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "8");
+  }
+
+  #[test]
+  fn example_part_two_with_junk_pipes() {
+    let input = Puzzle::parse(EXAMPLE_JUNK).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "10");
+  }
+}
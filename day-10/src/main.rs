@@ -1,24 +1,23 @@
-// Load Local Modules
-mod read;
-
 // Imports
-use day_10::PipeMap;
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_10::Puzzle;
 
 fn main() {
 
-    let input = read::read_chars("input.txt".to_owned());
-    
-    let mut map = PipeMap::new(input);
-    map.compute_solution();
-    let map_loop = map.find_loop();
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let max_distance = map.find_loop_distances(&map_loop).into_iter()
-        .max_by_key(|elem| elem.1)
-        .unwrap();
-    println!("\r🔧 Coordinate with max distance: '{}' with '{}' distance (Part 1)", max_distance.0, max_distance.1);
+    let max_distance = Puzzle::part_one(&input);
+    println!("\r🔧 Max distance along the loop: '{}' (Part 1)", max_distance);
 
     // Part 2
-    let possible_spots = map.find_inside_spots(&map_loop).len();
+    let possible_spots = Puzzle::part_two(&input);
     println!("\r🔧 Number of free spots inside loop: '{}' (Part 2)", possible_spots);
 }
\ No newline at end of file
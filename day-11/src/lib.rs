@@ -1,91 +1,68 @@
 use std::collections::{HashSet, HashMap};
+use aoc_common::error::InputError;
+use aoc_common::grid::{Coordinate, Grid};
+use aoc_common::solver::{Answer, Solver};
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
-pub type CoordinateUnit = i64;
-
-#[derive(Clone, Copy, Debug)]
-pub struct Coordinate {
-  x: CoordinateUnit,
-  y: CoordinateUnit
-}
+pub type CoordinateUnit = aoc_common::grid::CoordinateUnit;
 
+#[derive(Clone)]
 pub struct GalaxyMap {
   jump: CoordinateUnit,
   galaxies: Vec<Coordinate>,
+  width: usize,
+  height: usize,
 }
 
+pub struct Puzzle;
+
 // ==================================================== CONSTANTS ====================================================
 
 // =============================================== AUXILIARY FUNCTIONS ===============================================
 
 // ================================================= IMPLEMENTATIONS =================================================
-impl Coordinate {
-  fn new(x: CoordinateUnit, y: CoordinateUnit) -> Coordinate {
-    Coordinate { x, y }
-  }
-
-  fn distance(&self, other: &Self) -> CoordinateUnit {
-    let x_distance = (self.x - other.x).abs();
-    let y_distance = (self.y - other.y).abs();
-
-    x_distance + y_distance
-  }
-}
-
-impl std::ops::Add for Coordinate {
-  type Output = Coordinate;
-
-  fn add(self, other: Coordinate) -> Coordinate {
-    let new_x: CoordinateUnit = self.x + other.x;
-    let new_y: CoordinateUnit = self.y + other.y;
-    
-    Coordinate::new(new_x, new_y)
-  }
-}
-
-impl std::fmt::Display for Coordinate {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-      write!(f, "({}, {})", self.x, self.y)
-    }
-}
-
 impl GalaxyMap {
 
   const SPOT_EMPTY: char = '.';
   const SPOT_GALAXY: char = '#';
 
-  pub fn new(lines: &Vec<Vec<char>>, jump: CoordinateUnit) -> GalaxyMap {
-    let mut galaxies: Vec<Coordinate> = Vec::new();
+  pub fn new(text: &str, jump: CoordinateUnit) -> Result<GalaxyMap, InputError> {
+    let map: Grid<bool> = Grid::parse_text(text, |galaxy_char| match galaxy_char {
+      GalaxyMap::SPOT_EMPTY => Some(false),
+      GalaxyMap::SPOT_GALAXY => Some(true),
+      _ => None,
+    })?;
+    let galaxies: Vec<Coordinate> = map.positions(|&galaxy| galaxy).collect();
 
-    for (line_index, galaxy_line) in lines.into_iter().enumerate() {
-      for (row_index, galaxy_char) in galaxy_line.into_iter().enumerate() {
-        match galaxy_char.to_owned() {
-          GalaxyMap::SPOT_EMPTY => (),
-          GalaxyMap::SPOT_GALAXY => galaxies.push(Coordinate::new(row_index as CoordinateUnit, line_index as CoordinateUnit)),
-          other_symbol => panic!("🚨 Symbol not recognized '{}' as a cosmic symbol", other_symbol)
-        }
-      }
-    }
+    Ok(GalaxyMap { galaxies, jump, width: map.width(), height: map.height() })
+  }
+
+  pub fn with_jump(&self, jump: CoordinateUnit) -> GalaxyMap {
+    GalaxyMap { jump, ..self.clone() }
+  }
 
-    GalaxyMap { galaxies, jump }
+  fn render(&self) -> String {
+    let mut map: Grid<char> = Grid::filled(self.width, self.height, GalaxyMap::SPOT_EMPTY);
+    self.galaxies.iter().for_each(|galaxy| map[*galaxy] = GalaxyMap::SPOT_GALAXY);
+    map.to_string().trim_end_matches('\n').to_owned()
   }
 
   fn find_empty_ys(&self) -> HashSet<CoordinateUnit> {
     let current_ys: HashSet<CoordinateUnit> = self.galaxies.iter()
       .map(|coordinate| coordinate.y)
       .collect();
-    let max_y: CoordinateUnit = current_ys.iter().max().unwrap().to_owned();
+    let Some(&max_y) = current_ys.iter().max() else { return HashSet::new() };
 
-    (0..=max_y).into_iter().filter(|y| !current_ys.contains(y)).collect()
+    (0..=max_y).filter(|y| !current_ys.contains(y)).collect()
   }
 
   fn find_empty_xs(&self) -> HashSet<CoordinateUnit> {
     let current_xs: HashSet<CoordinateUnit> = self.galaxies.iter()
       .map(|coordinate| coordinate.x)
       .collect();
-    let max_x: CoordinateUnit = current_xs.iter().max().unwrap().to_owned();
+    let Some(&max_x) = current_xs.iter().max() else { return HashSet::new() };
 
-    (0..=max_x).into_iter().filter(|x| !current_xs.contains(x)).collect()
+    (0..=max_x).filter(|x| !current_xs.contains(x)).collect()
   }
 
   fn update_galaxy(&self, index: usize, empty_ys: &HashSet<CoordinateUnit>, empty_xs: &HashSet<CoordinateUnit>) -> Coordinate {
@@ -100,7 +77,7 @@ impl GalaxyMap {
     let empty_ys = self.find_empty_ys();
     let empty_xs = self.find_empty_xs();
 
-    let new_galaxies: Vec<Coordinate> = (0..self.galaxies.len()).into_iter()
+    let new_galaxies: Vec<Coordinate> = (0..self.galaxies.len())
       .map(|galaxy_index| self.update_galaxy(galaxy_index, &empty_ys, &empty_xs))
       .collect();
 
@@ -115,7 +92,7 @@ impl GalaxyMap {
       let galaxy = self.galaxies.get(galaxy_index).unwrap();
       for other_index in (galaxy_index + 1)..number_galaxies {
         let other_galaxy = self.galaxies.get(other_index).unwrap();
-        let distance = galaxy.distance(other_galaxy);
+        let distance = galaxy.manhattan_distance(other_galaxy);
 
         distance_map.insert((galaxy_index, other_index), distance);
       }
@@ -123,4 +100,72 @@ impl GalaxyMap {
 
     distance_map
   }
-}
\ No newline at end of file
+}
+
+impl Solver for Puzzle {
+  type Input = GalaxyMap;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    GalaxyMap::new(text, 1)
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.render()
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    let mut map = input.with_jump(2);
+    map.do_iteration();
+    map.compute_distances().into_values().sum::<CoordinateUnit>().into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    let mut map = input.with_jump(1000000);
+    map.do_iteration();
+    map.compute_distances().into_values().sum::<CoordinateUnit>().into()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+  fn sum_distances(input: &GalaxyMap, jump: CoordinateUnit) -> CoordinateUnit {
+    let mut map = input.with_jump(jump);
+    map.do_iteration();
+    map.compute_distances().into_values().sum()
+  }
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "374");
+  }
+
+  #[test]
+  fn example_older_galaxies() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(sum_distances(&input, 10), 1030);
+    assert_eq!(sum_distances(&input, 100), 8410);
+  }
+
+  #[test]
+  fn empty_space_has_no_distances() {
+    let input = Puzzle::parse("..\n..").unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "0");
+    assert_eq!(Puzzle::part_two(&input).to_string(), "0");
+  }
+}
@@ -1,26 +1,23 @@
-// Load Local Modules
-mod read;
-
 // Imports
-use day_11::GalaxyMap;
-use day_11::CoordinateUnit;
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_11::Puzzle;
 
 fn main() {
 
-    let input = read::read_chars("input.txt".to_owned());
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let mut map = GalaxyMap::new(&input, 2);
-    map.do_iteration();
-    let sum_shortest_paths: CoordinateUnit = map.compute_distances().into_iter()
-        .map(|(_, distance)| distance).sum();
+    let sum_shortest_paths = Puzzle::part_one(&input);
     println!("\r🌟 Sum shortest paths between galaxies: '{}' (Part 1)", sum_shortest_paths);
 
     // Part 2
-    let jump = 1000000;
-    let mut map = GalaxyMap::new(&input, jump);
-    map.do_iteration();
-    let sum_shortest_paths: CoordinateUnit = map.compute_distances().into_iter()
-        .map(|(_, distance)| distance).sum();
-    println!("\r🌟 Sum shortest paths between galaxies: '{}' with jumps of '{}' (Part 2)", sum_shortest_paths, jump);
+    let sum_shortest_paths = Puzzle::part_two(&input);
+    println!("\r🌟 Sum shortest paths between galaxies: '{}' with jumps of '1000000' (Part 2)", sum_shortest_paths);
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
 use std::{hash::{Hash, Hasher}, collections::{hash_map::DefaultHasher, HashMap}};
 
 use itertools::intersperse;
+use aoc_common::error::InputError;
+use aoc_common::solver::{Answer, Solver};
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
@@ -29,37 +31,64 @@ struct SolutionCheatSheet {
   map: HashMap<SolutionCheatSheetKey, usize>
 }
 
+pub struct Puzzle;
+
 // ==================================================== CONSTANTS ====================================================
 
 // =============================================== AUXILIARY FUNCTIONS ===============================================
 
 // ================================================= IMPLEMENTATIONS =================================================
-impl From<char> for SpringStatus {
-  fn from(value: char) -> Self {
+impl SpringStatus {
+  fn parse(value: char) -> Option<Self> {
     match value {
-      '.' => SpringStatus::Operational,
-      '#' => SpringStatus::Damaged,
-      '?' => SpringStatus::Unknown,
-      unknown_char => panic!("🚨 Char '{}' was not recognized as a valid spring status", unknown_char)
+      '.' => Some(SpringStatus::Operational),
+      '#' => Some(SpringStatus::Damaged),
+      '?' => Some(SpringStatus::Unknown),
+      _ => None,
+    }
+  }
+}
+
+impl From<SpringStatus> for char {
+  fn from(value: SpringStatus) -> Self {
+    match value {
+      SpringStatus::Operational => '.',
+      SpringStatus::Damaged => '#',
+      SpringStatus::Unknown => '?',
     }
   }
 }
 
 impl SpringRecord {
-  pub fn new(line: &String, repetitions: usize) -> SpringRecord {
-    let mut line_split = line.split_whitespace();
+  pub fn parse(line_index: usize, line: &str) -> Result<SpringRecord, InputError> {
+    let error = |token: &str, reason: &str| InputError::at_token(line_index + 1, line, token, reason);
+    let line_split: Vec<&str> = line.split_whitespace().collect();
+    let [springs, hint_numbers] = line_split[..] else {
+      return Err(error(line, "line is not formatted as 'springs hint'"));
+    };
 
     // Compute record
-    let record_fold: Record = line_split.next().unwrap()
-      .chars().map(SpringStatus::from)
-      .collect();
-    let record: Record = intersperse((0..repetitions).map(|_| record_fold.clone()), vec![SpringStatus::Unknown]).flatten().collect();
+    let record: Record = springs.chars()
+      .map(SpringStatus::parse)
+      .collect::<Option<_>>()
+      .ok_or_else(|| error(springs, "springs are not one of '.#?'"))?;
     // Compute hint
-    let hint_fold: Vec<usize> = line_split.next().unwrap()
-      .split(",")
-      .map(|number| number.parse().unwrap())
-      .collect();
-    let hint: Vec<usize> = (0..repetitions).map(|_| hint_fold.clone()).flatten().collect();
+    let hint: Vec<usize> = hint_numbers.split(",")
+      .map(|number| number.parse().map_err(|_| error(number, "hint is not a list of numbers separated by ','")))
+      .collect::<Result<_, _>>()?;
+
+    Ok(SpringRecord { record, hint, solutions: None })
+  }
+
+  fn render(&self) -> String {
+    let record: String = self.record.iter().map(|&status| char::from(status)).collect();
+    let hint: Vec<String> = self.hint.iter().map(|number| number.to_string()).collect();
+    format!("{} {}", record, hint.join(","))
+  }
+
+  pub fn unfold(&self, repetitions: usize) -> SpringRecord {
+    let record: Record = intersperse((0..repetitions).map(|_| self.record.clone()), vec![SpringStatus::Unknown]).flatten().collect();
+    let hint: Vec<usize> = (0..repetitions).flat_map(|_| self.hint.clone()).collect();
 
     SpringRecord { record, hint, solutions: None }
   }
@@ -72,12 +101,12 @@ impl SpringRecord {
     if computed_pattern_size == 0 && solution.current_pattern.is_none() { return true; }
 
     let hint_splice = &self.hint[0..computed_pattern_size];
-    return solution.set_pattern.eq(hint_splice) &&
-      solution.current_pattern.map_or(true, |pattern| pattern.le(self.hint.get(computed_pattern_size).unwrap()));
+    solution.set_pattern.eq(hint_splice) &&
+      solution.current_pattern.is_none_or(|pattern| pattern.le(self.hint.get(computed_pattern_size).unwrap()))
   }
 
   fn filter_final_solution(&self, solution: &SolutionBuilder) -> bool {
-    solution.set_pattern.eq(&self.hint) && matches!(solution.current_pattern, None)
+    solution.set_pattern.eq(&self.hint) && solution.current_pattern.is_none()
   }
 
   fn recursive_solution_finder(&self, current_index: usize, current_solution: SolutionBuilder, cheat_sheet: &mut SolutionCheatSheet) -> usize {
@@ -188,4 +217,147 @@ impl SolutionCheatSheet {
     let key = self.compute_key(entry_index, entry_solution);
     self.map.get(&key).map(|s| s.to_owned())
   }
-}
\ No newline at end of file
+}
+
+impl Solver for Puzzle {
+  type Input = Vec<SpringRecord>;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    text.lines().enumerate()
+      .map(|(line_index, line)| SpringRecord::parse(line_index, line))
+      .collect()
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.iter()
+      .map(SpringRecord::render)
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    input.iter()
+      .map(|problem| problem.unfold(1))
+      .map(|mut problem| { problem.compute_solutions(); problem.get_solutions() })
+      .sum::<usize>()
+      .into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    input.iter()
+      .map(|problem| problem.unfold(5))
+      .map(|mut problem| { problem.compute_solutions(); problem.get_solutions() })
+      .sum::<usize>()
+      .into()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use aoc_common::random::{differential, Random};
+
+  const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+  fn count_arrangements(line: &str, repetitions: usize) -> usize {
+    let mut record = SpringRecord::parse(0, line).unwrap().unfold(repetitions);
+    record.compute_solutions();
+    record.get_solutions()
+  }
+
+  #[test]
+  fn arrangements_per_record() {
+    let counts: Vec<usize> = EXAMPLE.lines().map(|line| count_arrangements(line, 1)).collect();
+    assert_eq!(counts, vec![1, 4, 1, 1, 4, 10]);
+  }
+
+  #[test]
+  fn arrangements_per_unfolded_record() {
+    let counts: Vec<usize> = EXAMPLE.lines().map(|line| count_arrangements(line, 5)).collect();
+    assert_eq!(counts, vec![1, 16384, 1, 16, 2500, 506250]);
+  }
+
+  #[test]
+  fn malformed_records_are_rejected() {
+    for text in ["???.###", "???.### 1,,3", "???.### 1 1", "??x.### 1,1,3", "???.### 1,a"] {
+      assert!(Puzzle::parse(text).is_err(), "🚨 Record should not parse: {}", text);
+    }
+  }
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "21");
+  }
+
+  #[test]
+  fn example_part_two() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "525152");
+  }
+
+  /// Unknown springs the brute force may have to assign, so the unfolded records stay quick to enumerate
+  const MAX_NAIVE_UNKNOWNS: usize = 16;
+
+  /// Record hiding at least one damaged spring, its hint taken from the springs before some become unknown
+  fn random_record(random: &mut Random) -> String {
+    let springs: Vec<char> = loop {
+      let springs: Vec<char> = (0..random.range(1, 10)).map(|_| *random.pick(&['.', '#'])).collect();
+      if springs.contains(&'#') { break springs }
+    };
+    let hint: Vec<String> = springs.split(|&spring| spring == '.')
+      .filter(|group| !group.is_empty())
+      .map(|group| group.len().to_string())
+      .collect();
+
+    let record: String = springs.into_iter()
+      .map(|spring| if random.below(2) == 0 { '?' } else { spring })
+      .collect();
+    format!("{} {}", record, hint.join(","))
+  }
+
+  /// Reference for the memoised search: every assignment of the unknown springs checked against the hint
+  fn naive_arrangements(record: &SpringRecord) -> usize {
+    let unknowns: Vec<usize> = (0..record.record.len())
+      .filter(|&index| record.record[index] == SpringStatus::Unknown)
+      .collect();
+
+    (0..1usize << unknowns.len())
+      .filter(|assignment| {
+        let mut springs = record.record.clone();
+        for (bit, &index) in unknowns.iter().enumerate() {
+          springs[index] = if assignment >> bit & 1 == 1 { SpringStatus::Damaged } else { SpringStatus::Operational };
+        }
+        let groups: Vec<usize> = springs.split(|&spring| spring == SpringStatus::Operational)
+          .filter(|group| !group.is_empty())
+          .map(|group| group.len())
+          .collect();
+        groups == record.hint
+      })
+      .count()
+  }
+
+  /// Record and unfolding with few enough unknown springs for the brute force
+  fn random_unfolded_record(random: &mut Random) -> (String, usize) {
+    loop {
+      let (line, repetitions) = (random_record(random), random.range(1, 2) as usize);
+      let record = SpringRecord::parse(0, &line).unwrap().unfold(repetitions);
+      let unknowns = record.record.iter().filter(|&&spring| spring == SpringStatus::Unknown).count();
+      if unknowns <= MAX_NAIVE_UNKNOWNS { break (line, repetitions) }
+    }
+  }
+
+  #[test]
+  fn memoised_search_matches_brute_force() {
+    differential(1000, random_unfolded_record,
+      |(line, repetitions)| naive_arrangements(&SpringRecord::parse(0, line).unwrap().unfold(*repetitions)),
+      |(line, repetitions)| count_arrangements(line, *repetitions));
+  }
+}
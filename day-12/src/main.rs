@@ -1,24 +1,23 @@
-// Load Local Modules
-mod read;
-
 // Imports
-use day_12::SpringRecord;
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_12::Puzzle;
 
 fn main() {
 
-    let input = read::read_lines("input.txt".to_owned());
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let mut problems: Vec<SpringRecord> = input.iter()
-        .map(|line| SpringRecord::new(line, 1)).collect();
-    problems.iter_mut().for_each(SpringRecord::compute_solutions);
-    let sum_arrangements: usize = problems.iter().map(SpringRecord::get_solutions).sum();
+    let sum_arrangements = Puzzle::part_one(&input);
     println!("\r🏥 Number of possible arrangements: '{}' (Part 1)", sum_arrangements);
 
     // Part 2
-    let mut problems: Vec<SpringRecord> = input.iter()
-        .map(|line| SpringRecord::new(line, 5)).collect();
-    problems.iter_mut().for_each(SpringRecord::compute_solutions);
-    let sum_arrangements: usize = problems.iter().map(SpringRecord::get_solutions).sum();
+    let sum_arrangements = Puzzle::part_two(&input);
     println!("\r🏥 Number of possible arrangements: '{}' (Part 2)", sum_arrangements);
 }
\ No newline at end of file
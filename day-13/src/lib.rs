@@ -1,14 +1,11 @@
-use std::collections::HashMap;
+use aoc_common::error::InputError;
+use aoc_common::grid::Grid;
+use aoc_common::read;
+use aoc_common::solver::{Answer, Solver};
 
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
-pub type CoordinateUnit = i64;
-
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-pub struct Coordinate {
-  x: CoordinateUnit,
-  y: CoordinateUnit
-}
+pub type CoordinateUnit = aoc_common::grid::CoordinateUnit;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum PatternType {
@@ -17,7 +14,7 @@ enum PatternType {
 }
 
 pub struct Problem {
-  map: HashMap<Coordinate, PatternType>,
+  map: Grid<PatternType>,
   size_x: CoordinateUnit,
   size_y: CoordinateUnit,
 }
@@ -32,77 +29,54 @@ struct Solution {
   direction: SolutionDirection
 }
 
+pub struct Puzzle;
+
 // ==================================================== CONSTANTS ====================================================
 
 // =============================================== AUXILIARY FUNCTIONS ===============================================
-
-// ================================================= IMPLEMENTATIONS =================================================
-impl Coordinate {
-  fn new(x: CoordinateUnit, y: CoordinateUnit) -> Coordinate {
-    Coordinate { x, y }
-  }
+fn count_differences<'a>(first: impl Iterator<Item = &'a PatternType>, second: impl Iterator<Item = &'a PatternType>) -> usize {
+  first.zip(second)
+    .filter(|(first, second)| first != second)
+    .count()
 }
 
-impl std::ops::Add for Coordinate {
-  type Output = Coordinate;
-
-  fn add(self, other: Coordinate) -> Coordinate {
-    let new_x: CoordinateUnit = self.x + other.x;
-    let new_y: CoordinateUnit = self.y + other.y;
-    
-    Coordinate::new(new_x, new_y)
-  }
-}
-
-impl std::fmt::Display for Coordinate {
-  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    write!(f, "({}, {})", self.x, self.y)
+// ================================================= IMPLEMENTATIONS =================================================
+impl PatternType {
+  fn parse(value: char) -> Option<Self> {
+    match value {
+      '.' => Some(PatternType::Ash),
+      '#' => Some(PatternType::Rock),
+      _ => None,
+    }
   }
 }
 
-impl From<char> for PatternType {
-  fn from(value: char) -> Self {
+impl From<PatternType> for char {
+  fn from(value: PatternType) -> Self {
     match value {
-      '.' => PatternType::Ash,
-      '#' => PatternType::Rock,
-      unknown_char => panic!("🚨 Char '{}' was not recognized as a valid pattern", unknown_char)
+      PatternType::Ash => '.',
+      PatternType::Rock => '#',
     }
   }
 }
 
 impl Problem {
-  pub fn new(lines: Vec<Vec<char>>) -> Problem {
-    let mut map: HashMap<Coordinate, PatternType> = HashMap::new();
-    let size_x = lines.first().map_or(0, Vec::len) as CoordinateUnit;
-    let size_y = lines.len() as CoordinateUnit;
-
-    for (line_index, map_line) in lines.into_iter().enumerate() {
-      for (row_index, map_element) in map_line.into_iter().enumerate() {
-        let coordinate = Coordinate::new(row_index as CoordinateUnit, line_index as CoordinateUnit);
-        map.insert(coordinate, PatternType::from(map_element));
-      }
-    }
+  pub fn new(text: &str) -> Result<Problem, InputError> {
+    let map: Grid<PatternType> = Grid::parse_text(text, PatternType::parse)?;
+    let size_x = map.width() as CoordinateUnit;
+    let size_y = map.height() as CoordinateUnit;
 
-    Problem { map, size_x: size_x, size_y }
+    Ok(Problem { map, size_x, size_y })
   }
 
   fn check_vertical_mirror(&self, lower_bound: CoordinateUnit, difference_match: usize) -> Option<Solution> {
     let upper_bound = lower_bound + 1;
-    let delta_check = CoordinateUnit::min(lower_bound - 0, self.size_x - 1 - upper_bound);
-
-    let result: usize = (0..self.size_y).map(|test_y| {
-      (0..=delta_check).map(|delta_x| {
-        let first_coordinate = Coordinate::new(lower_bound - delta_x, test_y);
-        let first = self.map.get(&first_coordinate).unwrap().to_owned();
-
-        let second_coordinate = Coordinate::new(upper_bound + delta_x, test_y);
-        let second = self.map.get(&second_coordinate).unwrap().to_owned();
+    let delta_check = CoordinateUnit::min(lower_bound, self.size_x - 1 - upper_bound);
 
-        match first.eq(&second) {
-          true => 0,
-          false => 1
-        }
-      }).sum::<usize>()
+    let result: usize = (0..=delta_check).map(|delta_x| {
+      let first = self.map.column((lower_bound - delta_x) as usize);
+      let second = self.map.column((upper_bound + delta_x) as usize);
+      count_differences(first, second)
     }).sum();
 
     match result == difference_match {
@@ -113,22 +87,13 @@ impl Problem {
 
   fn check_horizontal_mirror(&self, lower_bound: CoordinateUnit, difference_match: usize) -> Option<Solution> {
     let upper_bound = lower_bound + 1;
-    let delta_check = CoordinateUnit::min(lower_bound - 0, self.size_y - 1 - upper_bound);
+    let delta_check = CoordinateUnit::min(lower_bound, self.size_y - 1 - upper_bound);
 
-    let result = (0..self.size_x).map(|test_x| {
-      (0..=delta_check).map(|delta_y| {
-        let first_coordinate = Coordinate::new(test_x, lower_bound - delta_y);
-        let first = self.map.get(&first_coordinate).unwrap().to_owned();
-
-        let second_coordinate = Coordinate::new(test_x, upper_bound + delta_y);
-        let second = self.map.get(&second_coordinate).unwrap().to_owned();
-
-        match first.eq(&second) {
-          true => 0,
-          false => 1
-        }
-      }).sum::<usize>()
-    }).sum::<usize>();
+    let result: usize = (0..=delta_check).map(|delta_y| {
+      let first = self.map.row((lower_bound - delta_y) as usize).iter();
+      let second = self.map.row((upper_bound + delta_y) as usize).iter();
+      count_differences(first, second)
+    }).sum();
 
     match result == difference_match {
       true => Some(Solution { lower_bound, direction: SolutionDirection::Horizontal }),
@@ -149,13 +114,98 @@ impl Problem {
     None
   }
 
-  pub fn get_summary_number(&self, difference_match: Option<usize>) -> CoordinateUnit {
-    match self.find_first_mirror(difference_match.unwrap_or(0)) {
-      None => panic!("🚨 No mirror line was found!"),
-      Some(solution) => match solution.direction {
+  /// None when the pattern has no mirror line
+  pub fn get_summary_number(&self, difference_match: Option<usize>) -> Option<CoordinateUnit> {
+    self.find_first_mirror(difference_match.unwrap_or(0))
+      .map(|solution| match solution.direction {
         SolutionDirection::Vertical => solution.lower_bound + 1,
         SolutionDirection::Horizontal => (solution.lower_bound + 1) * 100
-      }
-    }
+      })
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = Vec<Problem>;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    read::split_blocks_indexed(text).into_iter()
+      .map(|(line_index, block)| Problem::new(block).map_err(|error| error.below(line_index)))
+      .collect()
   }
-}
\ No newline at end of file
+
+  fn render(input: &Self::Input) -> String {
+    input.iter()
+      .map(|problem| problem.map.to_string())
+      .collect::<Vec<String>>()
+      .join("\n")
+      .trim_end_matches('\n')
+      .to_owned()
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    input.iter().map(|problem| problem.get_summary_number(None)).sum::<Option<CoordinateUnit>>()
+      .map_or_else(|| Answer::unsolved("A pattern has no mirror line"), Answer::from)
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    input.iter().map(|problem| problem.get_summary_number(Some(1))).sum::<Option<CoordinateUnit>>()
+      .map_or_else(|| Answer::unsolved("A pattern has no smudged mirror line"), Answer::from)
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+  #[test]
+  fn summary_per_pattern() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    let clean: Vec<Option<CoordinateUnit>> = input.iter().map(|problem| problem.get_summary_number(None)).collect();
+    let smudged: Vec<Option<CoordinateUnit>> = input.iter().map(|problem| problem.get_summary_number(Some(1))).collect();
+    assert_eq!(clean, vec![Some(5), Some(400)]);
+    assert_eq!(smudged, vec![Some(300), Some(100)]);
+  }
+
+  #[test]
+  fn patterns_without_mirror_are_unsolved() {
+    let input = Puzzle::parse("#.\n.#").unwrap();
+    assert!(matches!(Puzzle::part_one(&input), Answer::Unsolved(_)));
+    assert!(matches!(Puzzle::part_two(&input), Answer::Unsolved(_)));
+  }
+
+  #[test]
+  fn errors_are_located_in_the_whole_text() {
+    let text = "#.\n.#\n\n##\n#";
+    assert_eq!(Puzzle::parse(text).err().unwrap().to_string(), "🚨 Invalid input '#' at 5:2: line is narrower than 2");
+  }
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "405");
+  }
+
+  #[test]
+  fn example_part_two() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "400");
+  }
+}
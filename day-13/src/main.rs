@@ -1,21 +1,23 @@
-// Load Local Modules
-mod read;
-
 // Imports
-use day_13::Problem;
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_13::Puzzle;
 
 fn main() {
 
-    let input = read::read_chars("input.txt".to_owned());
-    let problems: Vec<Problem> = input.split(Vec::is_empty).into_iter()
-        .map(|map_lines| Problem::new(map_lines.to_vec()))
-        .collect();
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let summary_reflection: i64 = problems.iter().map(|p| Problem::get_summary_number(p, None)).sum();
+    let summary_reflection = Puzzle::part_one(&input);
     println!("\r🪨  Sumamry reflection number: '{}' (Part 1)", summary_reflection);
 
     // Part 2
-    let summary_reflection_diff_1: i64 = problems.iter().map(|p| Problem::get_summary_number(p, Some(1))).sum();
+    let summary_reflection_diff_1 = Puzzle::part_two(&input);
     println!("\r🪨  Sumamry reflection number: '{}' (Part 2)", summary_reflection_diff_1);
 }
\ No newline at end of file
@@ -1,29 +1,25 @@
 use std::borrow::BorrowMut;
 use std::collections::HashMap;
-use std::collections::HashSet;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::Hash;
 use std::hash::Hasher;
+use aoc_common::error::InputError;
+use aoc_common::grid::{Coordinate, Grid};
+use aoc_common::solver::{Answer, Solver};
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
-pub type CoordinateUnit = i64;
+pub type CoordinateUnit = aoc_common::grid::CoordinateUnit;
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
-pub struct Coordinate {
-  x: CoordinateUnit,
-  y: CoordinateUnit
-}
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 enum PlatformPosition {
   Empty,
   RoundRock,
   SquareRock
 }
 
+#[derive(Clone)]
 pub struct Platform {
-  square_rocks: HashSet<Coordinate>,
-  round_rocks: HashSet<Coordinate>,
+  map: Grid<PlatformPosition>,
   size_x: CoordinateUnit,
   size_y: CoordinateUnit,
 }
@@ -36,6 +32,8 @@ pub enum TiltDirection {
   West
 }
 
+pub struct Puzzle;
+
 // ==================================================== CONSTANTS ====================================================
 
 // =============================================== AUXILIARY FUNCTIONS ===============================================
@@ -49,36 +47,13 @@ fn sort_coordinate_based_direction(direction: TiltDirection, coordinate: &Coordi
 }
 
 // ================================================= IMPLEMENTATIONS =================================================
-impl Coordinate {
-  fn new(x: CoordinateUnit, y: CoordinateUnit) -> Coordinate {
-    Coordinate { x, y }
-  }
-}
-
-impl std::ops::Add for Coordinate {
-  type Output = Coordinate;
-
-  fn add(self, other: Coordinate) -> Coordinate {
-    let new_x: CoordinateUnit = self.x + other.x;
-    let new_y: CoordinateUnit = self.y + other.y;
-    
-    Coordinate::new(new_x, new_y)
-  }
-}
-
-impl std::fmt::Display for Coordinate {
-  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    write!(f, "({}, {})", self.x, self.y)
-  }
-}
-
-impl From<char> for PlatformPosition {
-  fn from(value: char) -> Self {
+impl PlatformPosition {
+  fn parse(value: char) -> Option<Self> {
     match value {
-      '.' => PlatformPosition::Empty,
-      'O' => PlatformPosition::RoundRock,
-      '#' => PlatformPosition::SquareRock,
-      unknown_char => panic!("🚨 Char '{}' was not recognized as a valid platform position", unknown_char)
+      '.' => Some(PlatformPosition::Empty),
+      'O' => Some(PlatformPosition::RoundRock),
+      '#' => Some(PlatformPosition::SquareRock),
+      _ => None,
     }
   }
 }
@@ -94,36 +69,19 @@ impl From<PlatformPosition> for char {
 }
 
 impl Platform {
-  pub fn new(lines: &Vec<Vec<char>>) -> Platform {
-    let mut square_rocks: HashSet<Coordinate> = HashSet::new();
-    let mut round_rocks: HashSet<Coordinate> = HashSet::new();
-
-    let size_x = lines.first().map_or(0, Vec::len) as CoordinateUnit;
-    let size_y = lines.len() as CoordinateUnit;
-
-    for (line_index, map_line) in lines.into_iter().enumerate() {
-      for (row_index, &map_element) in map_line.into_iter().enumerate() {
-        let coordinate = Coordinate::new(row_index as CoordinateUnit, line_index as CoordinateUnit);
-        let platform_type = PlatformPosition::from(map_element);
-        
-        match platform_type {
-          PlatformPosition::Empty => (),
-          PlatformPosition::SquareRock => { square_rocks.insert(coordinate); },
-          PlatformPosition::RoundRock => { round_rocks.insert(coordinate); },
-        }
-      }
-    }
+  pub fn new(text: &str) -> Result<Platform, InputError> {
+    let map: Grid<PlatformPosition> = Grid::parse_text(text, PlatformPosition::parse)?;
+    let size_x = map.width() as CoordinateUnit;
+    let size_y = map.height() as CoordinateUnit;
 
-    Platform { square_rocks, round_rocks, size_x, size_y }
+    Ok(Platform { map, size_x, size_y })
   }
 
-  fn coordinate_inside(&self, coordinate: &Coordinate) -> bool {
-    let inside_x = coordinate.x >= 0 && coordinate.x < self.size_x;
-    let inside_y = coordinate.y >= 0 && coordinate.y < self.size_y;
-    inside_x && inside_y
+  fn round_rocks(&self) -> impl Iterator<Item = Coordinate> + '_ {
+    self.map.positions(|&position| position == PlatformPosition::RoundRock)
   }
 
-  fn find_position_after_tilt(&self, direction: TiltDirection, start_coordinate: &Coordinate, current_round_rocks: &HashSet<Coordinate>) -> Coordinate {
+  fn find_position_after_tilt(&self, direction: TiltDirection, start_coordinate: &Coordinate) -> Coordinate {
     fn map_direction_delta(direction: TiltDirection) -> Coordinate {
       match direction {
         TiltDirection::North => Coordinate::new(0, -1),
@@ -137,7 +95,7 @@ impl Platform {
     let mut current_coordinate = start_coordinate.to_owned();
   
     let mut test_coordinate = current_coordinate + delta;
-    while self.coordinate_inside(&test_coordinate) && !self.square_rocks.contains(&test_coordinate) && !current_round_rocks.contains(&test_coordinate) {
+    while self.map.get(&test_coordinate) == Some(&PlatformPosition::Empty) {
       current_coordinate = test_coordinate;
       test_coordinate = current_coordinate + delta;
     }
@@ -146,17 +104,16 @@ impl Platform {
   }
 
   pub fn tilt_platform(&mut self, direction: TiltDirection) {
-    // Move Round Rocksosition)
-    let mut round_rocks_position: Vec<Coordinate> = self.round_rocks.iter().map(&Coordinate::to_owned).collect();
+    // Move Round Rocks, starting with the ones closest to the tilted side
+    let mut round_rocks_position: Vec<Coordinate> = self.round_rocks().collect();
     round_rocks_position.sort_by_key(|elem: &Coordinate| sort_coordinate_based_direction(direction, elem));
 
-    self.round_rocks = round_rocks_position.into_iter()
-      .fold(HashSet::<Coordinate>::new(), |mut acc, coordinate| {
-        let new_position = self.find_position_after_tilt(direction, &coordinate, &acc);
-        acc.insert(new_position);
-        acc
-      }).into_iter()
-      .collect();
+    round_rocks_position.into_iter()
+      .for_each(|coordinate| {
+        let new_position = self.find_position_after_tilt(direction, &coordinate);
+        self.map[coordinate] = PlatformPosition::Empty;
+        self.map[new_position] = PlatformPosition::RoundRock;
+      });
   }
 
   pub fn compute_load(&self, direction: TiltDirection) -> CoordinateUnit {
@@ -169,8 +126,8 @@ impl Platform {
       }
     }
 
-    self.round_rocks.iter()
-      .map(|position| compute_position_load(position, self.size_x, self.size_y, direction))
+    self.round_rocks()
+      .map(|position| compute_position_load(&position, self.size_x, self.size_y, direction))
       .sum()
   }
 
@@ -188,13 +145,11 @@ impl Platform {
     while cycle_count != cycles {
       // Do cycle
       self.cycle();
-      cycle_count = cycle_count + 1;
+      cycle_count += 1;
 
       // Hash and Save
       let mut hasher = DefaultHasher::new();
-      let mut round_rocks: Vec<Coordinate> = self.round_rocks.iter().map(&Coordinate::to_owned).collect();
-      round_rocks.sort();
-      round_rocks.iter().for_each(|&coordinate| coordinate.hash(&mut hasher));
+      self.map.hash(&mut hasher);
       let hash = hasher.finish();
 
       let value = cheat_sheet.entry(hash).or_insert((None, None));
@@ -224,21 +179,138 @@ impl Platform {
 
 impl std::fmt::Display for Platform {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    for value_y in 0..self.size_y {
-      for value_x in 0..self.size_x {
-        let coordinate = Coordinate::new(value_x, value_y);
-        let platform_type = match 1 {
-          _ if self.round_rocks.contains(&coordinate) => PlatformPosition::RoundRock,
-          _ if self.square_rocks.contains(&coordinate) => PlatformPosition::SquareRock,
-          _ => PlatformPosition::Empty
-        };
-
-        let _ = write!(f, "{}", char::from(platform_type));
+    write!(f, "{}", self.map)
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = Platform;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    Platform::new(text)
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.to_string().trim_end_matches('\n').to_owned()
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    let mut platform = input.clone();
+    platform.tilt_platform(TiltDirection::North);
+    platform.compute_load(TiltDirection::North).into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    let mut platform = input.clone();
+    platform.n_cycles(1000000000);
+    platform.compute_load(TiltDirection::North).into()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use aoc_common::random::{differential, Random};
+
+  const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+  const EXAMPLE_ONE_CYCLE: &str = "\
+.....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....
+";
+
+  #[test]
+  fn single_spin_cycle() {
+    let mut platform = Puzzle::parse(EXAMPLE).unwrap();
+    platform.cycle();
+    assert_eq!(platform.to_string(), EXAMPLE_ONE_CYCLE);
+  }
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "136");
+  }
+
+  #[test]
+  fn example_part_two() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "64");
+  }
+
+  const MAX_NAIVE_CYCLES: u64 = 150;
+
+  fn random_platform(random: &mut Random) -> String {
+    let (width, height) = (random.range(1, 7), random.range(1, 7));
+    let rows: Vec<String> = (0..height)
+      .map(|_| (0..width).map(|_| *random.pick(&['.', '.', 'O', 'O', '#'])).collect())
+      .collect();
+    rows.join("\n")
+  }
+
+  /// Reference tilt: every round rock rolls one cell at a time until none can move
+  fn naive_tilt(rows: &mut [Vec<char>], (dx, dy): (isize, isize)) {
+    let (width, height) = (rows[0].len() as isize, rows.len() as isize);
+    let mut moved = true;
+    while moved {
+      moved = false;
+      for y in 0..height {
+        for x in 0..width {
+          let (to_x, to_y) = (x + dx, y + dy);
+          if rows[y as usize][x as usize] != 'O' || !(0..width).contains(&to_x) || !(0..height).contains(&to_y) {
+            continue;
+          }
+          if rows[to_y as usize][to_x as usize] == '.' {
+            rows[to_y as usize][to_x as usize] = 'O';
+            rows[y as usize][x as usize] = '.';
+            moved = true;
+          }
+        }
       }
+    }
+  }
 
-      let _ = writeln!(f, "");
+  /// Reference for the cycle skipping: every spin cycle run one after the other
+  fn naive_load_after_cycles(text: &str, cycles: u64) -> usize {
+    let mut rows: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+    for _ in 0..cycles {
+      for delta in [(0, -1), (-1, 0), (0, 1), (1, 0)] {
+        naive_tilt(&mut rows, delta);
+      }
     }
 
-    Ok(())
+    rows.iter().enumerate()
+      .map(|(y, row)| row.iter().filter(|&&cell| cell == 'O').count() * (rows.len() - y))
+      .sum()
+  }
+
+  #[test]
+  fn cycle_skipping_matches_every_cycle() {
+    differential(300, |random| (random_platform(random), random.range(1, MAX_NAIVE_CYCLES)),
+      |(text, cycles)| naive_load_after_cycles(text, *cycles),
+      |(text, cycles)| {
+        let mut platform = Puzzle::parse(text).unwrap();
+        platform.n_cycles(*cycles as usize);
+        platform.compute_load(TiltDirection::North) as usize
+      });
   }
-}
\ No newline at end of file
+}
@@ -1,24 +1,23 @@
-// Load Local Modules
-mod read;
-
 // Imports
-use day_14::TiltDirection;
-use day_14::Platform;
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_14::Puzzle;
 
 fn main() {
 
-    let input = read::read_chars("input.txt".to_owned());
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let mut platform: Platform = Platform::new(&input);
-    platform.tilt_platform(TiltDirection::North);
-    let load_north = platform.compute_load(TiltDirection::North);
+    let load_north = Puzzle::part_one(&input);
     println!("\r🪨  Load on North side: '{}' (Part 1)", load_north);
 
     // Part 2
-    let cycles: usize = 1000000000;
-    let mut platform: Platform = Platform::new(&input);
-    platform.n_cycles(cycles);
-    let load_north = platform.compute_load(TiltDirection::North);
-    println!("\r🪨  Load on North side after '{}' cycles: '{}' (Part 2)", cycles, load_north);
+    let load_north = Puzzle::part_two(&input);
+    println!("\r🪨  Load on North side after '1000000000' cycles: '{}' (Part 2)", load_north);
 }
\ No newline at end of file
@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+use aoc_common::error::InputError;
+use aoc_common::solver::{Answer, Solver};
 
 // =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
 pub type HashValue = u32;
@@ -22,6 +24,8 @@ pub struct BoxLine {
   boxes: HashMap<HashValue, Vec<Lens>>
 }
 
+pub struct Puzzle;
+
 // ==================================================== CONSTANTS ====================================================
 const OPERATION_REMOVAL: char = '-';
 const OPERATION_ADDITION: char = '=';
@@ -34,26 +38,30 @@ pub fn custom_hash(string: &&str) -> HashValue {
 }
 
 // ================================================= IMPLEMENTATIONS =================================================
-impl From<&str> for CommandOperation {
-  fn from(value: &str) -> Self {
-    match value {
-      "" => CommandOperation::Removal,
-      _ => CommandOperation::Addition { focal_length: value.parse().unwrap() },
-    }
+impl Command {
+
+  /// Labels are lowercase letters, focal lengths a single digit from 1 through 9
+  fn parse(step: &str) -> Option<Command> {
+    let split = step.find([OPERATION_REMOVAL, OPERATION_ADDITION])?;
+    let (label, operation) = step.split_at(split);
+    if label.is_empty() || !label.chars().all(|characther| characther.is_ascii_lowercase()) { return None }
+
+    let operation = match operation.split_at(1) {
+      (_, "") if operation.starts_with(OPERATION_REMOVAL) => CommandOperation::Removal,
+      (_, focal_length) if operation.starts_with(OPERATION_ADDITION) && focal_length.len() == 1 =>
+        CommandOperation::Addition { focal_length: focal_length.parse().ok().filter(|&focal_length| focal_length > 0)? },
+      _ => return None,
+    };
+
+    Some(Command { label: label.to_owned(), operation })
   }
 }
 
-impl From<&str> for Command {
-  fn from(value: &str) -> Self {
-    let split: Vec<&str> = match 1 {
-      _ if value.contains(OPERATION_REMOVAL) => value.split(OPERATION_REMOVAL),
-      _ if value.contains(OPERATION_ADDITION) => value.split(OPERATION_ADDITION),
-      _ => panic!("🚨 Weird it looks like the command does not contain either '{}' or '{}'", OPERATION_REMOVAL, OPERATION_ADDITION)
-    }.collect();
-
-    Self {
-      label: split.get(0).unwrap().to_string(),
-      operation: CommandOperation::from(split.get(1).unwrap().to_owned())
+impl std::fmt::Display for Command {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self.operation {
+      CommandOperation::Removal => write!(f, "{}{}", self.label, OPERATION_REMOVAL),
+      CommandOperation::Addition { focal_length } => write!(f, "{}{}{}", self.label, OPERATION_ADDITION, focal_length),
     }
   }
 }
@@ -67,7 +75,7 @@ impl Lens {
 impl BoxLine {
   pub fn new() -> BoxLine {
     BoxLine {
-      boxes: (0..256).into_iter()
+      boxes: (0..256)
         .map(|v| (v, Vec::new()))
         .collect()
     }
@@ -84,10 +92,10 @@ impl BoxLine {
     let box_index = custom_hash(&label.as_str());
     let lens_index = self.find_label_box(box_index, &label);
 
-    lens_index.map(|lens_index| {
+    if let Some(lens_index) = lens_index {
       let lens_box = self.boxes.get_mut(&box_index).unwrap();
       lens_box.remove(lens_index);
-    });
+    }
   }
 
   fn process_command_addition(&mut self, label: String, focal_length: FocalLength) {
@@ -109,10 +117,10 @@ impl BoxLine {
     }
   }
 
-  pub fn process_command(&mut self, command: Command) {
+  pub fn process_command(&mut self, command: &Command) {
     match command.operation {
-      CommandOperation::Removal => self.process_command_remove(command.label),
-      CommandOperation::Addition { focal_length } => self.process_command_addition(command.label, focal_length)
+      CommandOperation::Removal => self.process_command_remove(command.label.clone()),
+      CommandOperation::Addition { focal_length } => self.process_command_addition(command.label.clone(), focal_length)
     }
   }
 
@@ -123,4 +131,84 @@ impl BoxLine {
           .map(move |(lens_index, lens)| (box_index as FocalLength + 1) * (lens_index as FocalLength + 1) * lens.focal_length))
       .sum()
   }
-}
\ No newline at end of file
+}
+
+impl Default for BoxLine {
+  fn default() -> Self {
+    BoxLine::new()
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = Vec<Command>;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    let mut lines = text.lines();
+    let line = lines.next().unwrap_or("");
+    if let Some(extra_line) = lines.next() {
+      return Err(InputError::parse(2, 1, extra_line, "sequence is not on a single line"));
+    }
+
+    let mut column: usize = 1;
+    line.split_terminator(",")
+      .map(|step| {
+        let command = Command::parse(step)
+          .ok_or_else(|| InputError::parse(1, column, step, "step is not a label followed by '-' or '=1' to '=9'"));
+        column += step.chars().count() + 1;
+        command
+      })
+      .collect()
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.iter()
+      .map(|command| command.to_string())
+      .collect::<Vec<String>>()
+      .join(",")
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    input.iter().map(|command| custom_hash(&command.to_string().as_str())).sum::<HashValue>().into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    let mut box_line: BoxLine = BoxLine::new();
+    input.iter()
+      .for_each(|command| box_line.process_command(command));
+    box_line.compute_focal_length().into()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+  #[test]
+  fn malformed_steps_are_rejected() {
+    for text in ["rn=1,,cm-", "rn=10", "rn=0", "Rn=1", "rn", "cm-1", "=1", "rn=1\ncm-"] {
+      assert!(Puzzle::parse(text).is_err(), "🚨 Sequence should not parse: {}", text);
+    }
+  }
+
+  #[test]
+  fn hash_of_a_word() {
+    assert_eq!(custom_hash(&"HASH"), 52);
+    assert_eq!(custom_hash(&"rn"), 0);
+    assert_eq!(custom_hash(&"qp"), 1);
+  }
+
+  #[test]
+  fn example_part_one() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_one(&input).to_string(), "1320");
+  }
+
+  #[test]
+  fn example_part_two() {
+    let input = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Puzzle::part_two(&input).to_string(), "145");
+  }
+}
@@ -1,24 +1,23 @@
-// Load Local Modules
-mod read;
-
 // Imports
-use day_16::Coordinate;
-use day_16::Direction;
-use day_16::ReflectionMap;
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_16::Puzzle;
 
 fn main() {
 
-    let input = read::read_chars("input.txt".to_owned());
-    let reflection_map = ReflectionMap::new(&input);
-    
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
     // Part 1
-    let start_position = Coordinate::new(0, 0);
-    let start_direction = Direction::Right;
-    let count_positions = reflection_map.get_energized_count(start_position, start_direction);
+    let count_positions = Puzzle::part_one(&input);
     println!("\r⚡ Number of different positions: '{}' (Part 1)", count_positions);
 
     // Part 2
-    let most_energized = reflection_map.get_most_energizable_starting_point();
-    println!("\r⚡ Most enerizable state: '{}' ({}, {:?}) (Part 2)", most_energized.2, most_energized.0, most_energized.1);
-
+    let most_energized = Puzzle::part_two(&input);
+    println!("\r⚡ Most energizable state: '{}' (Part 2)", most_energized);
 }
\ No newline at end of file
@@ -0,0 +1,164 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use aoc_common::error::InputError;
+use aoc_common::grid::{Coordinate, CoordinateUnit, Direction, Grid};
+use aoc_common::solver::{Answer, Solver};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+pub type HeatLoss = u32;
+
+/// Straight-run constraints of a crucible: it must move at least 'min_run' blocks before turning or stopping,
+/// and at most 'max_run' blocks before turning
+#[derive(Clone, Copy, Debug)]
+pub struct Crucible {
+  pub min_run: usize,
+  pub max_run: usize,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, PartialOrd, Ord)]
+struct CrucibleState {
+  position: Coordinate,
+  direction: Direction,
+  run_length: usize,
+}
+
+pub struct CityMap {
+  heat_loss: Grid<HeatLoss>,
+}
+
+pub struct Puzzle;
+
+// ==================================================== CONSTANTS ====================================================
+pub const NORMAL_CRUCIBLE: Crucible = Crucible { min_run: 1, max_run: 3 };
+pub const ULTRA_CRUCIBLE: Crucible = Crucible { min_run: 4, max_run: 10 };
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl CrucibleState {
+  fn step(self, direction: Direction) -> CrucibleState {
+    let run_length = if direction == self.direction { self.run_length + 1 } else { 1 };
+    CrucibleState { position: self.position + direction.delta(), direction, run_length }
+  }
+
+  /// States reachable in one block, going straight while the run allows it or turning once it is long enough
+  fn next_states(self, crucible: Crucible) -> Vec<CrucibleState> {
+    let mut directions = Vec::with_capacity(3);
+    if self.run_length < crucible.max_run {
+      directions.push(self.direction);
+    }
+    if self.run_length >= crucible.min_run {
+      directions.push(self.direction.turn_left());
+      directions.push(self.direction.turn_right());
+    }
+
+    directions.into_iter().map(|direction| self.step(direction)).collect()
+  }
+}
+
+impl CityMap {
+  pub fn new(text: &str) -> Result<CityMap, InputError> {
+    let heat_loss = Grid::parse_text(text, |block| block.to_digit(10))?;
+    Ok(CityMap { heat_loss })
+  }
+
+  /// Dijkstra from the top-left block to the bottom-right one, the state tracking the direction and how many
+  /// blocks the crucible has moved straight so the run constraints can be checked. None when it cannot arrive
+  pub fn minimum_heat_loss(&self, crucible: Crucible) -> Option<HeatLoss> {
+    let target = Coordinate::new(self.heat_loss.width() as CoordinateUnit - 1, self.heat_loss.height() as CoordinateUnit - 1);
+    let mut best: HashMap<CrucibleState, HeatLoss> = HashMap::new();
+    let mut to_process: BinaryHeap<Reverse<(HeatLoss, CrucibleState)>> = [Direction::Right, Direction::Down].iter()
+      .map(|&direction| Reverse((0, CrucibleState { position: Coordinate::new(0, 0), direction, run_length: 0 })))
+      .collect();
+
+    while let Some(Reverse((heat_loss, state))) = to_process.pop() {
+      if state.position == target && state.run_length >= crucible.min_run {
+        return Some(heat_loss);
+      }
+      if best.get(&state).is_some_and(|&known| known < heat_loss) {
+        continue;
+      }
+
+      for next_state in state.next_states(crucible) {
+        let Some(&block_loss) = self.heat_loss.get(&next_state.position) else { continue };
+        let next_loss = heat_loss + block_loss;
+        if best.get(&next_state).is_none_or(|&known| next_loss < known) {
+          best.insert(next_state, next_loss);
+          to_process.push(Reverse((next_loss, next_state)));
+        }
+      }
+    }
+
+    None
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = CityMap;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    CityMap::new(text)
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.heat_loss.rows()
+      .map(|row| row.iter().map(|&block| char::from_digit(block, 10).unwrap()).collect::<String>())
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    input.minimum_heat_loss(NORMAL_CRUCIBLE)
+      .map_or_else(|| Answer::unsolved("The crucible cannot reach the factory"), Answer::from)
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    input.minimum_heat_loss(ULTRA_CRUCIBLE)
+      .map_or_else(|| Answer::unsolved("The ultra crucible cannot reach the factory"), Answer::from)
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+  const EXAMPLE_ULTRA: &str = "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+
+  #[test]
+  fn example_part_one() {
+    assert_eq!(Puzzle::part_one(&Puzzle::parse(EXAMPLE).unwrap()).to_string(), "102");
+  }
+
+  #[test]
+  fn example_part_two() {
+    assert_eq!(Puzzle::part_two(&Puzzle::parse(EXAMPLE).unwrap()).to_string(), "94");
+    assert_eq!(Puzzle::part_two(&Puzzle::parse(EXAMPLE_ULTRA).unwrap()).to_string(), "71");
+  }
+
+  #[test]
+  fn unreachable_factory_has_no_path() {
+    let map = Puzzle::parse("12\n34").unwrap();
+    assert_eq!(map.minimum_heat_loss(ULTRA_CRUCIBLE), None);
+    assert_eq!(map.minimum_heat_loss(NORMAL_CRUCIBLE), Some(6));
+    assert!(matches!(Puzzle::part_two(&map), Answer::Unsolved(_)));
+  }
+}
@@ -0,0 +1,23 @@
+// Imports
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_17::Puzzle;
+
+fn main() {
+
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
+    // Part 1
+    let heat_loss = Puzzle::part_one(&input);
+    println!("\r🔥 Least heat loss with a crucible: '{}' (Part 1)", heat_loss);
+
+    // Part 2
+    let ultra_heat_loss = Puzzle::part_two(&input);
+    println!("\r🔥 Least heat loss with an ultra crucible: '{}' (Part 2)", ultra_heat_loss);
+}
@@ -0,0 +1,167 @@
+use aoc_common::error::InputError;
+use aoc_common::grid::{Coordinate, CoordinateUnit, Direction};
+use aoc_common::solver::{Answer, Solver};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct DigStep {
+  pub direction: Direction,
+  pub length: CoordinateUnit,
+}
+
+/// Every line holds two readings of the same step: the plain one and the one hidden in the colour code
+pub struct DigPlan {
+  pub steps: Vec<DigStep>,
+  pub hex_steps: Vec<DigStep>,
+}
+
+pub struct Puzzle;
+
+// ==================================================== CONSTANTS ====================================================
+const HEX_LENGTH_DIGITS: usize = 5;
+/// Each direction with the letter of the plain reading and the digit ending the colour code
+const DIRECTION_SYMBOLS: [(Direction, char, char); 4] = [
+  (Direction::Up, 'U', '3'), (Direction::Down, 'D', '1'), (Direction::Right, 'R', '0'), (Direction::Left, 'L', '2')];
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+fn parse_direction(symbol: &str, hex: bool) -> Option<Direction> {
+  DIRECTION_SYMBOLS.iter()
+    .find(|&&(_, letter, digit)| symbol.chars().eq([if hex { digit } else { letter }]))
+    .map(|&(direction, _, _)| direction)
+}
+
+fn direction_symbol(direction: Direction, hex: bool) -> char {
+  DIRECTION_SYMBOLS.iter()
+    .find(|&&(other, _, _)| other == direction)
+    .map(|&(_, letter, digit)| if hex { digit } else { letter })
+    .unwrap()
+}
+
+fn parse_dig_step(line_index: usize, line: &str) -> Result<(DigStep, DigStep), InputError> {
+  let error = |token: &str, reason: &str| InputError::at_token(line_index + 1, line, token, reason);
+  let split: Vec<&str> = line.split_whitespace().collect();
+  let [direction_letter, length_value, color] = split[..] else {
+    return Err(error(line, "expected direction, length and color"));
+  };
+
+  let direction = parse_direction(direction_letter, false).ok_or_else(|| error(direction_letter, "unknown direction"))?;
+  let length = length_value.parse().ok()
+    .filter(|&length| length > 0)
+    .ok_or_else(|| error(length_value, "length is not a positive number"))?;
+
+  let hex = color.strip_prefix("(#").and_then(|color| color.strip_suffix(')'))
+    .filter(|hex| hex.len() == HEX_LENGTH_DIGITS + 1 && hex.is_ascii())
+    .ok_or_else(|| error(color, "color is not formatted as '(#rrggbb)'"))?;
+  let hex_length = Some(&hex[..HEX_LENGTH_DIGITS])
+    .filter(|digits| digits.chars().all(|digit| digit.is_ascii_hexdigit()))
+    .and_then(|digits| CoordinateUnit::from_str_radix(digits, 16).ok())
+    .ok_or_else(|| error(hex, "color is not hexadecimal"))?;
+  let hex_direction = parse_direction(&hex[HEX_LENGTH_DIGITS..], true)
+    .ok_or_else(|| error(&hex[HEX_LENGTH_DIGITS..], "color does not end in a direction digit"))?;
+
+  Ok((DigStep { direction, length }, DigStep { direction: hex_direction, length: hex_length }))
+}
+
+/// Cubic meters dug out, trench included. The shoelace formula gives the area enclosed by the trench
+/// centre line, and Pick's theorem turns it into the interior cell count: 'i = A - b / 2 + 1'. Adding the 'b'
+/// boundary cells leaves 'A + b / 2 + 1', so no grid is ever built however long the steps are
+pub fn lagoon_size(steps: &[DigStep]) -> CoordinateUnit {
+  let mut position = Coordinate::new(0, 0);
+  let mut double_area = 0;
+  let mut boundary = 0;
+  for step in steps {
+    let delta = step.direction.delta();
+    let next = Coordinate::new(position.x + delta.x * step.length, position.y + delta.y * step.length);
+    double_area += position.x * next.y - next.x * position.y;
+    boundary += step.length;
+    position = next;
+  }
+
+  (double_area.abs() + boundary) / 2 + 1
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl DigPlan {
+  pub fn new(text: &str) -> Result<DigPlan, InputError> {
+    let (steps, hex_steps) = text.lines().enumerate()
+      .map(|(line_index, line)| parse_dig_step(line_index, line))
+      .collect::<Result<Vec<_>, _>>()?
+      .into_iter()
+      .unzip();
+
+    Ok(DigPlan { steps, hex_steps })
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = DigPlan;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    DigPlan::new(text)
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.steps.iter().zip(&input.hex_steps)
+      .map(|(step, hex_step)| format!("{} {} (#{:0width$x}{})",
+        direction_symbol(step.direction, false), step.length,
+        hex_step.length, direction_symbol(hex_step.direction, true), width = HEX_LENGTH_DIGITS))
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    lagoon_size(&input.steps).into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    lagoon_size(&input.hex_steps).into()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+  #[test]
+  fn hex_colors_decode_to_steps() {
+    let plan = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(plan.hex_steps[0], DigStep { direction: Direction::Right, length: 461937 });
+    assert_eq!(plan.hex_steps[1], DigStep { direction: Direction::Down, length: 56407 });
+  }
+
+  #[test]
+  fn malformed_color_is_reported() {
+    assert!(Puzzle::parse("R 6 (#70c71)").is_err());
+    assert!(Puzzle::parse("X 6 (#70c710)").is_err());
+    assert!(Puzzle::parse("0 6 (#70c710)").is_err());
+    assert!(Puzzle::parse("R 0 (#70c710)").is_err());
+    assert!(Puzzle::parse("R 6 (#+0c710)").is_err());
+  }
+
+  #[test]
+  fn example_part_one() {
+    assert_eq!(Puzzle::part_one(&Puzzle::parse(EXAMPLE).unwrap()).to_string(), "62");
+  }
+
+  #[test]
+  fn example_part_two() {
+    assert_eq!(Puzzle::part_two(&Puzzle::parse(EXAMPLE).unwrap()).to_string(), "952408144115");
+  }
+}
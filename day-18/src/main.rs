@@ -0,0 +1,23 @@
+// Imports
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_18::Puzzle;
+
+fn main() {
+
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
+    // Part 1
+    let lagoon_size = Puzzle::part_one(&input);
+    println!("\r⛏️ Cubic meters of lava in the lagoon: '{}' (Part 1)", lagoon_size);
+
+    // Part 2
+    let hex_lagoon_size = Puzzle::part_two(&input);
+    println!("\r⛏️ Cubic meters of lava following the colors: '{}' (Part 2)", hex_lagoon_size);
+}
@@ -0,0 +1,410 @@
+use std::collections::HashMap;
+use aoc_common::error::InputError;
+use aoc_common::range::{RangeItem, RangeUnit};
+use aoc_common::solver::{Answer, Solver};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+pub type Rating = RangeUnit;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Category {
+  X,
+  M,
+  A,
+  S
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Comparison {
+  LessThan,
+  GreaterThan
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+enum Target {
+  Accept,
+  Reject,
+  Workflow(String)
+}
+
+struct Rule {
+  category: Category,
+  comparison: Comparison,
+  value: Rating,
+  target: Target,
+}
+
+struct Workflow {
+  rules: Vec<Rule>,
+  fallback: Target,
+}
+
+/// Ratings of a machine part, indexed by 'Category'
+type Part = [Rating; CATEGORY_COUNT];
+
+/// Every rating combination still flowing through the workflows, one inclusive range per 'Category'
+type PartRange = [RangeItem; CATEGORY_COUNT];
+
+pub struct System {
+  workflows: HashMap<String, Workflow>,
+  /// Workflow names in the order they are listed, so the system can be written back
+  order: Vec<String>,
+  parts: Vec<Part>,
+}
+
+pub struct Puzzle;
+
+// ==================================================== CONSTANTS ====================================================
+const CATEGORY_COUNT: usize = 4;
+const START_WORKFLOW: &str = "in";
+const MIN_RATING: Rating = 1;
+const MAX_RATING: Rating = 4000;
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+fn parse_rule(rule: &str) -> Option<Rule> {
+  let (condition, target) = rule.split_once(':')?;
+  let mut chars = condition.chars();
+  let category = Category::from(chars.next()?)?;
+  let comparison = match chars.next()? {
+    '<' => Comparison::LessThan,
+    '>' => Comparison::GreaterThan,
+    _ => return None,
+  };
+  let value = chars.as_str().parse().ok()?;
+
+  Some(Rule { category, comparison, value, target: Target::from(target)? })
+}
+
+fn is_workflow_name(name: &str) -> bool {
+  !name.is_empty() && name.chars().all(|characther| characther.is_ascii_lowercase())
+}
+
+fn parse_workflow(line_index: usize, line: &str) -> Result<(String, Workflow), InputError> {
+  let error = |token: &str, reason: &str| InputError::at_token(line_index + 1, line, token, reason);
+  let (name, body) = line.strip_suffix('}').and_then(|line| line.split_once('{'))
+    .filter(|&(name, _)| is_workflow_name(name))
+    .ok_or_else(|| error(line, "workflow is not formatted as 'name{rules}'"))?;
+
+  let mut rules: Vec<&str> = body.split(',').collect();
+  let fallback_target = rules.pop().unwrap_or(body);
+  let fallback = Target::from(fallback_target)
+    .ok_or_else(|| error(fallback_target, "workflow does not end with a fallback target"))?;
+  let rules = rules.into_iter()
+    .map(|rule| parse_rule(rule).ok_or_else(|| error(rule, "rule is not formatted as 'category<value:target'")))
+    .collect::<Result<_, _>>()?;
+
+  Ok((name.to_owned(), Workflow { rules, fallback }))
+}
+
+fn parse_part(line_index: usize, line: &str) -> Result<Part, InputError> {
+  let error = |token: &str| {
+    InputError::at_token(line_index + 1, line, token, "part is not formatted as '{x=..,m=..,a=..,s=..}'")
+  };
+  let ratings = line.strip_prefix('{').and_then(|line| line.strip_suffix('}')).ok_or_else(|| error(line))?;
+
+  let mut part: [Option<Rating>; CATEGORY_COUNT] = [None; CATEGORY_COUNT];
+  for rating in ratings.split(',') {
+    let (category_name, value) = rating.split_once('=').ok_or_else(|| error(rating))?;
+    let mut category_chars = category_name.chars();
+    let category = category_chars.next().and_then(Category::from)
+      .filter(|_| category_chars.next().is_none())
+      .ok_or_else(|| error(category_name))?;
+    let slot = &mut part[category as usize];
+    if slot.is_some() { return Err(error(category_name)) }
+    let rating_value = value.parse().ok().filter(|rating_value| (MIN_RATING..=MAX_RATING).contains(rating_value));
+    *slot = Some(rating_value.ok_or_else(|| error(value))?);
+  }
+
+  let mut ratings: Part = [0; CATEGORY_COUNT];
+  for (rating, value) in ratings.iter_mut().zip(part) {
+    *rating = value.ok_or_else(|| error(line))?;
+  }
+  Ok(ratings)
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Category {
+  fn from(symbol: char) -> Option<Category> {
+    match symbol {
+      'x' => Some(Category::X),
+      'm' => Some(Category::M),
+      'a' => Some(Category::A),
+      's' => Some(Category::S),
+      _ => None,
+    }
+  }
+
+  fn symbol(self) -> char {
+    ['x', 'm', 'a', 's'][self as usize]
+  }
+}
+
+impl Target {
+  fn from(target: &str) -> Option<Target> {
+    match target {
+      "A" => Some(Target::Accept),
+      "R" => Some(Target::Reject),
+      name if is_workflow_name(name) => Some(Target::Workflow(name.to_owned())),
+      _ => None,
+    }
+  }
+}
+
+impl std::fmt::Display for Target {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Target::Accept => write!(f, "A"),
+      Target::Reject => write!(f, "R"),
+      Target::Workflow(name) => write!(f, "{}", name),
+    }
+  }
+}
+
+impl Rule {
+  /// Ratings matching the rule as an inclusive range, None when no rating can match
+  fn window(&self) -> Option<RangeItem> {
+    match self.comparison {
+      Comparison::LessThan => self.value.checked_sub(1).map(|end| RangeItem::new(Rating::MIN, end)),
+      Comparison::GreaterThan => self.value.checked_add(1).map(|start| RangeItem::new(start, Rating::MAX)),
+    }
+  }
+
+  fn render(&self) -> String {
+    let comparison = match self.comparison { Comparison::LessThan => '<', Comparison::GreaterThan => '>' };
+    format!("{}{}{}:{}", self.category.symbol(), comparison, self.value, self.target)
+  }
+
+  fn matches(&self, part: &Part) -> bool {
+    self.window().is_some_and(|window| window.contains(part[self.category as usize]))
+  }
+
+  /// Splits the ratings into the ones sent to the rule target and the ones left for the following rules
+  fn split(&self, ratings: PartRange) -> (Option<PartRange>, Vec<PartRange>) {
+    let category = self.category as usize;
+    let Some(window) = self.window() else { return (None, vec![ratings]) };
+    let (before, between, after) = ratings[category].split(window);
+
+    let with_category = |range: RangeItem| {
+      let mut ratings = ratings;
+      ratings[category] = range;
+      ratings
+    };
+    (between.map(with_category), [before, after].into_iter().flatten().map(with_category).collect())
+  }
+}
+
+impl Workflow {
+  fn targets(&self) -> impl Iterator<Item = &Target> {
+    self.rules.iter().map(|rule| &rule.target).chain(std::iter::once(&self.fallback))
+  }
+
+  fn send(&self, part: &Part) -> &Target {
+    self.rules.iter()
+      .find(|rule| rule.matches(part))
+      .map_or(&self.fallback, |rule| &rule.target)
+  }
+}
+
+impl System {
+  pub fn new(text: &str) -> Result<System, InputError> {
+    let mut workflows = HashMap::new();
+    let mut order = Vec::new();
+    let mut parts = Vec::new();
+    let mut lines = text.lines().enumerate();
+
+    for (line_index, line) in lines.by_ref() {
+      if line.trim().is_empty() { break }
+      let (name, workflow) = parse_workflow(line_index, line)?;
+      if workflows.insert(name.clone(), workflow).is_some() {
+        return Err(InputError::parse(line_index + 1, 1, line, "workflow is listed twice"));
+      }
+      order.push(name);
+    }
+    for (line_index, line) in lines {
+      parts.push(parse_part(line_index, line)?);
+    }
+
+    let system = System { workflows, order, parts };
+    system.validate(text)?;
+    Ok(system)
+  }
+
+  /// Every target must exist and no workflow may lead back to itself, so parts always end accepted or rejected.
+  /// Errors point at the line declaring the workflow at fault, workflows being the first lines of the text
+  fn validate(&self, text: &str) -> Result<(), InputError> {
+    let error = |name: &str, token: Option<&str>, reason: &str| {
+      let line_index = self.order.iter().position(|declared| declared == name).unwrap_or(0);
+      let line = text.lines().nth(line_index).unwrap_or("");
+      let rule = token.and_then(|target| line.split(['{', ',', '}'])
+        .find(|rule| rule.rsplit(':').next() == Some(target)));
+      InputError::at_token(line_index + 1, line, rule.unwrap_or(line), reason)
+    };
+    if !self.workflows.contains_key(START_WORKFLOW) {
+      return Err(InputError::parse(1, 1, START_WORKFLOW, "starting workflow is missing"));
+    }
+
+    let mut finished: HashMap<&str, bool> = HashMap::new();
+    for name in self.workflows.keys() {
+      let mut to_process: Vec<(&str, bool)> = vec![(name, false)];
+      while let Some((name, leaving)) = to_process.pop() {
+        if leaving {
+          finished.insert(name, true);
+          continue;
+        }
+        match finished.get(name) {
+          Some(true) => continue,
+          Some(false) => return Err(error(name, None, "workflow leads back to itself")),
+          None => { finished.insert(name, false); }
+        }
+
+        to_process.push((name, true));
+        for target in self.workflows[name].targets() {
+          if let Target::Workflow(target_name) = target {
+            if !self.workflows.contains_key(target_name) {
+              return Err(error(name, Some(target_name), "target workflow does not exist"));
+            }
+            if finished.get(target_name.as_str()) != Some(&true) {
+              to_process.push((target_name, false));
+            }
+          }
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  fn render(&self) -> String {
+    let workflows = self.order.iter()
+      .map(|name| {
+        let workflow = &self.workflows[name];
+        let rules: Vec<String> = workflow.rules.iter().map(Rule::render)
+          .chain(std::iter::once(workflow.fallback.to_string()))
+          .collect();
+        format!("{}{{{}}}", name, rules.join(","))
+      });
+    let parts = self.parts.iter()
+      .map(|part| format!("{{x={},m={},a={},s={}}}", part[0], part[1], part[2], part[3]));
+
+    workflows
+      .chain(std::iter::once(String::new()))
+      .chain(parts)
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  fn is_accepted(&self, part: &Part) -> bool {
+    let mut target = Target::Workflow(START_WORKFLOW.to_owned());
+    loop {
+      match target {
+        Target::Accept => return true,
+        Target::Reject => return false,
+        Target::Workflow(name) => target = self.workflows[&name].send(part).clone(),
+      }
+    }
+  }
+
+  pub fn accepted_ratings_sum(&self) -> Rating {
+    self.parts.iter()
+      .filter(|part| self.is_accepted(part))
+      .map(|part| part.iter().sum::<Rating>())
+      .sum()
+  }
+
+  /// Walks the workflows with ranges of ratings instead of single parts, splitting them on every rule
+  fn count_accepted(&self, target: &Target, ratings: PartRange) -> u64 {
+    let workflow = match target {
+      Target::Accept => return ratings.iter().map(RangeItem::len).product(),
+      Target::Reject => return 0,
+      Target::Workflow(name) => &self.workflows[name],
+    };
+
+    let mut accepted = 0;
+    let mut remaining = vec![ratings];
+    for rule in &workflow.rules {
+      let mut not_matched = Vec::new();
+      for ratings in remaining {
+        let (matched, rest) = rule.split(ratings);
+        if let Some(matched) = matched {
+          accepted += self.count_accepted(&rule.target, matched);
+        }
+        not_matched.extend(rest);
+      }
+      remaining = not_matched;
+    }
+
+    accepted + remaining.into_iter()
+      .map(|ratings| self.count_accepted(&workflow.fallback, ratings))
+      .sum::<u64>()
+  }
+
+  pub fn accepted_combinations(&self) -> u64 {
+    let ratings = [RangeItem::new(MIN_RATING, MAX_RATING); CATEGORY_COUNT];
+    self.count_accepted(&Target::Workflow(START_WORKFLOW.to_owned()), ratings)
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = System;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    System::new(text)
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.render()
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    input.accepted_ratings_sum().into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    input.accepted_combinations().into()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+  #[test]
+  fn broken_workflows_are_reported() {
+    assert!(Puzzle::parse("in{x<10:nowhere,A}\n\n{x=1,m=2,a=3,s=4}").is_err());
+    assert!(Puzzle::parse("in{x<10:px,A}\npx{m>5:in,R}\n\n{x=1,m=2,a=3,s=4}").is_err());
+    assert!(Puzzle::parse("in{x<10:A,R}\nin{m>5:A,R}\n\n{x=1,m=2,a=3,s=4}").is_err());
+    assert!(Puzzle::parse("in{x<10:Px,R}\nPx{m>5:A,R}\n\n{x=1,m=2,a=3,s=4}").is_err());
+    assert!(Puzzle::parse("in{x<10:A,R}\n\n{x=1,m=2,a=3,s=4001}").is_err());
+    assert!(Puzzle::parse("in{x<10:A,A}\n\n{x=1,m=2,a=3}").is_err());
+  }
+
+  #[test]
+  fn example_part_one() {
+    assert_eq!(Puzzle::part_one(&Puzzle::parse(EXAMPLE).unwrap()).to_string(), "19114");
+  }
+
+  #[test]
+  fn example_part_two() {
+    assert_eq!(Puzzle::part_two(&Puzzle::parse(EXAMPLE).unwrap()).to_string(), "167409079868000");
+  }
+}
@@ -0,0 +1,23 @@
+// Imports
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_19::Puzzle;
+
+fn main() {
+
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
+    // Part 1
+    let ratings_sum = Puzzle::part_one(&input);
+    println!("\r📦 Sum of the accepted part ratings: '{}' (Part 1)", ratings_sum);
+
+    // Part 2
+    let combinations = Puzzle::part_two(&input);
+    println!("\r📦 Distinct accepted rating combinations: '{}' (Part 2)", combinations);
+}
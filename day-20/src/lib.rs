@@ -0,0 +1,327 @@
+use std::collections::{HashMap, VecDeque};
+use aoc_common::error::InputError;
+use aoc_common::math::chinese_remainder_theorem;
+use aoc_common::solver::{Answer, Solver};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+type ModuleIndex = usize;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum ModuleKind {
+  Broadcaster,
+  FlipFlop,
+  Conjunction,
+  /// Named only as a destination, like 'output' or 'rx'
+  Untyped
+}
+
+/// Destination of a module output, with the slot the sender occupies among the destination inputs
+#[derive(Clone, Copy, Debug)]
+struct Connection {
+  module: ModuleIndex,
+  input_slot: usize,
+}
+
+struct Module {
+  kind: ModuleKind,
+  destinations: Vec<Connection>,
+  inputs: Vec<ModuleIndex>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Pulse {
+  to: Connection,
+  high: bool,
+}
+
+pub struct Network {
+  names: Vec<String>,
+  modules: Vec<Module>,
+  /// Modules in the order they are declared, so the network can be written back
+  declared: Vec<ModuleIndex>,
+  broadcaster: ModuleIndex,
+}
+
+/// Flip-flop states and conjunction memories, which change with every button press
+struct NetworkState {
+  on: Vec<bool>,
+  memory: Vec<Vec<bool>>,
+  high_inputs: Vec<usize>,
+}
+
+pub struct Puzzle;
+
+// ==================================================== CONSTANTS ====================================================
+const BROADCASTER: &str = "broadcaster";
+const FINAL_MACHINE: &str = "rx";
+const WARM_UP_PRESSES: usize = 1000;
+const MAX_PERIOD_PRESSES: usize = 1 << 16;
+const MAX_PRESS_PULSES: usize = 1 << 16;
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+fn is_module_name(name: &str) -> bool {
+  !name.is_empty() && name.chars().all(|characther| characther.is_ascii_lowercase())
+}
+
+fn parse_module(line_index: usize, line: &str) -> Result<(ModuleKind, &str, Vec<&str>), InputError> {
+  let error = |token: &str, reason: &str| InputError::at_token(line_index + 1, line, token, reason);
+  let (module, destinations) = line.split_once(" -> ")
+    .ok_or_else(|| error(line, "module is not formatted as 'name -> destinations'"))?;
+
+  let (kind, name) = match module.split_at(module.find(|c: char| c.is_ascii_alphanumeric()).unwrap_or(0)) {
+    ("%", name) => (ModuleKind::FlipFlop, name),
+    ("&", name) => (ModuleKind::Conjunction, name),
+    ("", BROADCASTER) => (ModuleKind::Broadcaster, BROADCASTER),
+    _ => return Err(error(module, "module is neither the broadcaster, a flip-flop nor a conjunction")),
+  };
+  if !is_module_name(name) {
+    return Err(error(name, "module name is not made of lowercase letters"));
+  }
+
+  let destinations: Vec<&str> = destinations.split(", ").collect();
+  if let Some(destination) = destinations.iter().find(|destination| !is_module_name(destination)) {
+    return Err(error(destination, "destination name is not made of lowercase letters"));
+  }
+  Ok((kind, name, destinations))
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Network {
+  pub fn new(text: &str) -> Result<Network, InputError> {
+    let declared = text.lines().enumerate()
+      .map(|(line_index, line)| parse_module(line_index, line))
+      .collect::<Result<Vec<_>, _>>()?;
+
+    // Every name, declared or only used as a destination, gets an index
+    let mut names: Vec<String> = Vec::new();
+    let mut indexes: HashMap<&str, ModuleIndex> = HashMap::new();
+    let mut modules: Vec<Module> = Vec::new();
+    let mut declared_order: Vec<ModuleIndex> = Vec::new();
+    for &(_, name, ref destinations) in &declared {
+      for &name in std::iter::once(&name).chain(destinations) {
+        indexes.entry(name).or_insert_with(|| {
+          names.push(name.to_owned());
+          modules.push(Module { kind: ModuleKind::Untyped, destinations: Vec::new(), inputs: Vec::new() });
+          names.len() - 1
+        });
+      }
+    }
+
+    for ((line_index, line), (kind, name, destinations)) in text.lines().enumerate().zip(declared) {
+      let index = indexes[name];
+      if modules[index].kind != ModuleKind::Untyped {
+        return Err(InputError::at_token(line_index + 1, line, name, "module is declared twice"));
+      }
+      modules[index].kind = kind;
+      declared_order.push(index);
+
+      for destination in destinations {
+        let destination = indexes[destination];
+        let input_slot = modules[destination].inputs.len();
+        modules[destination].inputs.push(index);
+        modules[index].destinations.push(Connection { module: destination, input_slot });
+      }
+    }
+
+    let broadcaster = indexes.get(BROADCASTER).copied()
+      .filter(|&broadcaster| modules[broadcaster].kind == ModuleKind::Broadcaster)
+      .ok_or_else(|| InputError::parse(1, 1, BROADCASTER, "network has no broadcaster"))?;
+    Ok(Network { names, modules, declared: declared_order, broadcaster })
+  }
+
+  fn render(&self) -> String {
+    self.declared.iter()
+      .map(|&index| {
+        let module = &self.modules[index];
+        let prefix = match module.kind {
+          ModuleKind::FlipFlop => "%",
+          ModuleKind::Conjunction => "&",
+          ModuleKind::Broadcaster | ModuleKind::Untyped => "",
+        };
+        let destinations: Vec<&str> = module.destinations.iter()
+          .map(|connection| self.names[connection.module].as_str())
+          .collect();
+        format!("{}{} -> {}", prefix, self.names[index], destinations.join(", "))
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  fn initial_state(&self) -> NetworkState {
+    NetworkState {
+      on: vec![false; self.modules.len()],
+      memory: self.modules.iter().map(|module| vec![false; module.inputs.len()]).collect(),
+      high_inputs: vec![0; self.modules.len()],
+    }
+  }
+
+  /// Presses the button once, handing every pulse to 'observe' in the order they are processed. None when the
+  /// pulses are still going after 'MAX_PRESS_PULSES', as conjunctions wired in a loop can keep sending forever
+  fn press_button<F>(&self, state: &mut NetworkState, mut observe: F) -> Option<()>
+    where F: FnMut(&Pulse) {
+
+    let button = Pulse { to: Connection { module: self.broadcaster, input_slot: 0 }, high: false };
+    let mut to_process: VecDeque<Pulse> = VecDeque::from([button]);
+
+    for _ in 0..MAX_PRESS_PULSES {
+      let Some(pulse) = to_process.pop_front() else { return Some(()) };
+      observe(&pulse);
+      let index = pulse.to.module;
+      let module = &self.modules[index];
+
+      let output = match module.kind {
+        ModuleKind::Broadcaster => Some(pulse.high),
+        ModuleKind::FlipFlop if !pulse.high => {
+          state.on[index] = !state.on[index];
+          Some(state.on[index])
+        },
+        ModuleKind::Conjunction => {
+          let remembered = &mut state.memory[index][pulse.to.input_slot];
+          if *remembered != pulse.high {
+            *remembered = pulse.high;
+            if pulse.high { state.high_inputs[index] += 1 } else { state.high_inputs[index] -= 1 }
+          }
+          Some(state.high_inputs[index] != module.inputs.len())
+        },
+        ModuleKind::FlipFlop | ModuleKind::Untyped => None,
+      };
+
+      if let Some(high) = output {
+        to_process.extend(module.destinations.iter().map(|&to| Pulse { to, high }));
+      }
+    }
+    to_process.is_empty().then_some(())
+  }
+
+  pub fn pulse_product(&self, presses: usize) -> Option<usize> {
+    let mut state = self.initial_state();
+    let (mut low, mut high) = (0, 0);
+    for _ in 0..presses {
+      self.press_button(&mut state, |pulse| if pulse.high { high += 1 } else { low += 1 })?;
+    }
+
+    Some(low * high)
+  }
+
+  /// The final machine is fed by a single conjunction, which only sends a low pulse once all its inputs sent a
+  /// high one during the same press. Each input does so periodically, so the first press on which every period
+  /// lines up comes from the Chinese remainder theorem, as with the ghosts of day 8
+  pub fn presses_to_final_machine(&self) -> Option<usize> {
+    let final_machine = self.names.iter().position(|name| name == FINAL_MACHINE)?;
+    let [feeder] = self.modules[final_machine].inputs[..] else { return None };
+    if self.modules[feeder].kind != ModuleKind::Conjunction || self.modules[feeder].inputs.is_empty() {
+      return None;
+    }
+
+    let inputs = self.modules[feeder].inputs.len();
+    let mut high_presses: Vec<Vec<usize>> = vec![Vec::new(); inputs];
+    let mut state = self.initial_state();
+    for press in 1..=MAX_PERIOD_PRESSES {
+      self.press_button(&mut state, |pulse| if pulse.high && pulse.to.module == feeder {
+        let presses = &mut high_presses[pulse.to.input_slot];
+        if presses.last() != Some(&press) { presses.push(press) }
+      })?;
+      if high_presses.iter().all(|presses| presses.len() >= 2) { break }
+    }
+    if high_presses.iter().any(|presses| presses.len() < 2) {
+      return None;
+    }
+
+    let constants: Vec<i64> = high_presses.iter().map(|presses| presses[0] as i64).collect();
+    let coefficient: Vec<i64> = high_presses.iter().map(|presses| (presses[1] - presses[0]) as i64).collect();
+    let (solution, period) = chinese_remainder_theorem(&constants, &coefficient)?;
+
+    // The smallest solution may come before every input has gone high once
+    let first_possible = *constants.iter().max().unwrap();
+    let presses = match solution < first_possible {
+      true => solution + (first_possible - solution + period - 1) / period * period,
+      false => solution,
+    };
+    Some(presses as usize)
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = Network;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    Network::new(text)
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.render()
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    input.pulse_product(WARM_UP_PRESSES)
+      .map_or_else(|| Answer::unsolved("The pulses of a button press never die out"), Answer::from)
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    input.presses_to_final_machine()
+      .map_or_else(|| Answer::unsolved("The machine cannot be turned on with a periodic conjunction"), Answer::from)
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+  const EXAMPLE_OUTPUT: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+  /// Two inverted counters of periods 2 and 4 feeding the final machine through a conjunction
+  const EXAMPLE_FINAL_MACHINE: &str = "\
+broadcaster -> a
+%a -> b, inva
+%b -> invb
+&inva -> hub
+&invb -> hub
+&hub -> rx";
+
+  #[test]
+  fn malformed_modules_are_reported() {
+    assert!(Puzzle::parse("%a -> b").is_err());
+    assert!(Puzzle::parse("broadcaster -> a\n$a -> b").is_err());
+    assert!(Puzzle::parse("broadcaster -> a\n%a -> b\n&a -> b").is_err());
+    assert!(Puzzle::parse("broadcaster -> a\n%a -> b c").is_err());
+    assert!(Puzzle::parse("%a -> broadcaster").is_err());
+  }
+
+  #[test]
+  fn example_part_one() {
+    assert_eq!(Puzzle::part_one(&Puzzle::parse(EXAMPLE).unwrap()).to_string(), "32000000");
+    assert_eq!(Puzzle::part_one(&Puzzle::parse(EXAMPLE_OUTPUT).unwrap()).to_string(), "11687500");
+  }
+
+  #[test]
+  fn example_part_two() {
+    assert_eq!(Puzzle::part_two(&Puzzle::parse(EXAMPLE_FINAL_MACHINE).unwrap()).to_string(), "4");
+    assert!(matches!(Puzzle::part_two(&Puzzle::parse(EXAMPLE).unwrap()), Answer::Unsolved(_)));
+  }
+
+  #[test]
+  fn endless_pulses_are_unsolved() {
+    let input = Puzzle::parse("broadcaster -> a\n&a -> a, rx").unwrap();
+    assert!(matches!(Puzzle::part_one(&input), Answer::Unsolved(_)));
+    assert!(matches!(Puzzle::part_two(&input), Answer::Unsolved(_)));
+  }
+
+  #[test]
+  fn unfed_conjunction_never_turns_on_final_machine() {
+    let input = Puzzle::parse("broadcaster -> a\n%a -> a\n&b -> rx").unwrap();
+    assert!(matches!(Puzzle::part_two(&input), Answer::Unsolved(_)));
+  }
+}
@@ -0,0 +1,23 @@
+// Imports
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_20::Puzzle;
+
+fn main() {
+
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
+    // Part 1
+    let pulse_product = Puzzle::part_one(&input);
+    println!("\r📡 Product of low and high pulses: '{}' (Part 1)", pulse_product);
+
+    // Part 2
+    let presses = Puzzle::part_two(&input);
+    println!("\r📡 Button presses to turn the machine on: '{}' (Part 2)", presses);
+}
@@ -0,0 +1,216 @@
+use std::collections::HashSet;
+use aoc_common::error::InputError;
+use aoc_common::grid::{Coordinate, CoordinateUnit, Grid};
+use aoc_common::math::{checked_variations_steps, ValueReading};
+use aoc_common::solver::{Answer, Solver};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+pub struct Garden {
+  rocks: Grid<bool>,
+  start: Coordinate,
+}
+
+pub struct Puzzle;
+
+// ==================================================== CONSTANTS ====================================================
+const PLOT: char = '.';
+const ROCK: char = '#';
+const START: char = 'S';
+const PART_ONE_STEPS: usize = 64;
+const PART_TWO_STEPS: usize = 26501365;
+/// Samples taken one map apart to extrapolate, one more than a quadratic needs so its differences reach zero
+const EXTRAPOLATION_SAMPLES: usize = 4;
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Garden {
+  pub fn new(text: &str) -> Result<Garden, InputError> {
+    let tiles: Grid<char> = Grid::parse_text(text, |tile| [PLOT, ROCK, START].contains(&tile).then_some(tile))?;
+    let starts: Vec<Coordinate> = tiles.positions(|&tile| tile == START).collect();
+    let start = match starts[..] {
+      [start] => start,
+      [] => return Err(InputError::parse(1, 1, text.lines().next().unwrap_or(""), "no starting position")),
+      [_, second, ..] => return Err(InputError::parse(second.y as usize + 1, second.x as usize + 1,
+        &START.to_string(), "more than one starting position")),
+    };
+
+    Ok(Garden { rocks: tiles.map(|&tile| tile == ROCK), start })
+  }
+
+  fn render(&self) -> String {
+    let mut tiles: Grid<char> = self.rocks.map(|&rock| if rock { ROCK } else { PLOT });
+    tiles[self.start] = START;
+    tiles.to_string().trim_end_matches('\n').to_owned()
+  }
+
+  /// Plots first reached on each step, the map repeating itself in every direction when 'infinite'
+  fn first_reached_counts(&self, max_steps: usize, infinite: bool) -> Vec<usize> {
+    let (width, height) = (self.rocks.width() as CoordinateUnit, self.rocks.height() as CoordinateUnit);
+    let is_plot = |position: &Coordinate| match infinite {
+      true => !self.rocks[Coordinate::new(position.x.rem_euclid(width), position.y.rem_euclid(height))],
+      false => self.rocks.get(position).is_some_and(|&rock| !rock),
+    };
+
+    let mut visited: HashSet<Coordinate> = HashSet::from([self.start]);
+    let mut frontier: Vec<Coordinate> = vec![self.start];
+    let mut counts: Vec<usize> = vec![1];
+    for _ in 0..max_steps {
+      frontier = frontier.iter()
+        .flat_map(|&position| [Coordinate::NORTH, Coordinate::SOUTH, Coordinate::EAST, Coordinate::WEST]
+          .map(|delta| position + delta))
+        .filter(|position| is_plot(position) && visited.insert(*position))
+        .collect();
+      counts.push(frontier.len());
+    }
+
+    counts
+  }
+
+  /// A plot reached after 'n' steps can be reached again every two steps by going back and forth, so the plots
+  /// reachable on an exact step count are the ones first reached on a step of the same parity
+  fn reachable_plots_from_counts(counts: &[usize], steps: usize) -> usize {
+    counts.iter().take(steps + 1).skip(steps % 2).step_by(2).sum()
+  }
+
+  pub fn reachable_plots(&self, steps: usize, infinite: bool) -> usize {
+    Garden::reachable_plots_from_counts(&self.first_reached_counts(steps, infinite), steps)
+  }
+
+  /// On the infinite map the plots reachable after 'offset + n * size' steps grow quadratically with 'n', so
+  /// a few samples one map apart are enough to extrapolate through the finite differences of day 9. None when
+  /// the map is not square or the samples do not grow quadratically
+  pub fn infinite_reachable_plots(&self, steps: usize) -> Option<usize> {
+    let size = self.rocks.width();
+    if self.rocks.height() != size {
+      return None;
+    }
+
+    let offset = steps % size;
+    let sample_steps: Vec<usize> = (0..EXTRAPOLATION_SAMPLES).map(|sample| offset + sample * size).collect();
+    let last_sample_steps = *sample_steps.last().unwrap();
+    if steps <= last_sample_steps {
+      return Some(self.reachable_plots(steps, true));
+    }
+
+    let counts = self.first_reached_counts(last_sample_steps, true);
+    let samples: Vec<ValueReading> = sample_steps.iter()
+      .map(|&sample_steps| Garden::reachable_plots_from_counts(&counts, sample_steps) as ValueReading)
+      .collect();
+    // A quadratic has its third differences at zero, the last level the samples give
+    let levels = checked_variations_steps(samples)?;
+    if levels.len() > EXTRAPOLATION_SAMPLES {
+      return None;
+    }
+
+    // Rather than one map at a time, which takes millions of steps on tiny maps, every level is added at once:
+    // the last difference of level 'k' counts once per way of picking 'k' of the remaining maps with repetition.
+    // The last level is all zeroes, so it is left out
+    let remaining = ((steps - last_sample_steps) / size) as ValueReading;
+    let (mut plots, mut weight): (ValueReading, ValueReading) = (0, 1);
+    for (level, differences) in levels[..levels.len() - 1].iter().enumerate() {
+      if level > 0 {
+        weight = weight.checked_mul(remaining + level as ValueReading - 1)? / level as ValueReading;
+      }
+      plots = plots.checked_add(weight.checked_mul(*differences.last()?)?)?;
+    }
+
+    Some(plots as usize)
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = Garden;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    Garden::new(text)
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.render()
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    input.reachable_plots(PART_ONE_STEPS, false).into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    input.infinite_reachable_plots(PART_TWO_STEPS)
+      .map_or_else(|| Answer::unsolved("The reachable plots do not grow quadratically on a square map"), Answer::from)
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+  /// The example with a clear row and column through the start, like the real inputs
+  const EXAMPLE_CLEAR_CROSS: &str = "\
+...........
+......##.#.
+.###..#..#.
+..#.#...#..
+....#.#....
+.....S.....
+.##......#.
+.......##..
+.##.#.####.
+.##...#.##.
+...........";
+
+  #[test]
+  fn unknown_tiles_are_reported() {
+    assert!(Puzzle::parse("..S\n.x.").is_err());
+    assert!(Puzzle::parse("...\n.#.").is_err());
+    assert!(Puzzle::parse("S.S\n...").is_err());
+  }
+
+  #[test]
+  fn example_part_one() {
+    let garden = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(garden.reachable_plots(6, false), 16);
+  }
+
+  #[test]
+  fn example_infinite_steps() {
+    let garden = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(garden.reachable_plots(10, true), 50);
+    assert_eq!(garden.reachable_plots(50, true), 1594);
+    assert_eq!(garden.reachable_plots(100, true), 6536);
+  }
+
+  #[test]
+  fn extrapolation_matches_walking() {
+    let garden = Puzzle::parse(EXAMPLE_CLEAR_CROSS).unwrap();
+    for steps in [27, 137, 203] {
+      assert_eq!(garden.infinite_reachable_plots(steps), Some(garden.reachable_plots(steps, true)));
+    }
+  }
+
+  #[test]
+  fn irregular_gardens_are_not_extrapolated() {
+    let garden = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(garden.infinite_reachable_plots(PART_TWO_STEPS), None);
+    let garden = Puzzle::parse("...\n.S.").unwrap();
+    assert!(matches!(Puzzle::part_two(&garden), Answer::Unsolved(_)));
+  }
+
+  #[test]
+  fn tiny_gardens_are_extrapolated() {
+    // Without rocks every plot of the same parity within the step count can be reached
+    let garden = Puzzle::parse("S.\n..").unwrap();
+    assert_eq!(Puzzle::part_two(&garden).to_string(), (26501366 * 26501366_u64).to_string());
+  }
+}
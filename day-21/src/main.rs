@@ -0,0 +1,23 @@
+// Imports
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_21::Puzzle;
+
+fn main() {
+
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
+    // Part 1
+    let plots = Puzzle::part_one(&input);
+    println!("\r👣 Garden plots reachable in 64 steps: '{}' (Part 1)", plots);
+
+    // Part 2
+    let infinite_plots = Puzzle::part_two(&input);
+    println!("\r👣 Garden plots reachable on the infinite map: '{}' (Part 2)", infinite_plots);
+}
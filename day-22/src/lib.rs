@@ -0,0 +1,203 @@
+use std::collections::{HashMap, VecDeque};
+use aoc_common::error::InputError;
+use aoc_common::grid::{Coordinate, Coordinate3D, CoordinateUnit, Grid};
+use aoc_common::solver::{Answer, Solver};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+type BrickIndex = usize;
+
+/// Brick between two corner cubes, 'low' holding the smallest value of every axis
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Brick {
+  low: Coordinate3D,
+  high: Coordinate3D,
+}
+
+/// Bricks once settled, sorted bottom to top, and which ones rest on which
+pub struct BrickStack {
+  bricks: Vec<Brick>,
+  supports: Vec<Vec<BrickIndex>>,
+  supported_by: Vec<Vec<BrickIndex>>,
+}
+
+pub struct Puzzle;
+
+// ==================================================== CONSTANTS ====================================================
+const GROUND_LEVEL: CoordinateUnit = 0;
+/// Bounds on where bricks can be, keeping the height map small and the falling heights far from overflowing
+const MAX_FOOTPRINT: CoordinateUnit = 1000;
+const MAX_HEIGHT: CoordinateUnit = 1 << 20;
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+fn parse_brick(line_index: usize, line: &str) -> Result<Brick, InputError> {
+  let error = |reason: String| InputError::parse(line_index + 1, 1, line, reason);
+  let (start_text, end_text) = line.split_once('~')
+    .ok_or_else(|| error("brick is not formatted as 'x,y,z~x,y,z'".to_owned()))?;
+  let start: Coordinate3D = start_text.parse()
+    .map_err(|reason| InputError::at_token(line_index + 1, line, start_text, reason))?;
+  let end: Coordinate3D = end_text.parse()
+    .map_err(|reason| InputError::at_token(line_index + 1, line, end_text, reason))?;
+
+  let brick = Brick {
+    low: Coordinate3D::new(start.x.min(end.x), start.y.min(end.y), start.z.min(end.z)),
+    high: Coordinate3D::new(start.x.max(end.x), start.y.max(end.y), start.z.max(end.z)),
+  };
+  if brick.low.x < 0 || brick.low.y < 0 || brick.low.z <= GROUND_LEVEL {
+    return Err(error("brick is not above the ground".to_owned()));
+  }
+  if brick.high.x >= MAX_FOOTPRINT || brick.high.y >= MAX_FOOTPRINT || brick.high.z >= MAX_HEIGHT {
+    return Err(error(format!("brick is not within {}x{}x{}", MAX_FOOTPRINT, MAX_FOOTPRINT, MAX_HEIGHT)));
+  }
+  Ok(brick)
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Brick {
+  /// Cells covered when looking from above
+  fn footprint(&self) -> impl Iterator<Item = Coordinate> + '_ {
+    (self.low.x..=self.high.x).flat_map(move |x| (self.low.y..=self.high.y).map(move |y| Coordinate::new(x, y)))
+  }
+
+  fn dropped_to(&self, z: CoordinateUnit) -> Brick {
+    let fall = Coordinate3D::new(0, 0, self.low.z - z);
+    Brick { low: self.low - fall, high: self.high - fall }
+  }
+}
+
+impl BrickStack {
+  /// Lets the bricks fall lowest first, keeping the top height of every column and the brick reaching it
+  pub fn settle(snapshot: &[Brick]) -> BrickStack {
+    let mut falling = snapshot.to_vec();
+    falling.sort_by_key(|brick| brick.low.z);
+    let width = falling.iter().map(|brick| brick.high.x + 1).max().unwrap_or(0) as usize;
+    let depth = falling.iter().map(|brick| brick.high.y + 1).max().unwrap_or(0) as usize;
+    let mut height_map: Grid<Option<(CoordinateUnit, BrickIndex)>> = Grid::filled(width, depth, None);
+
+    let mut bricks: Vec<Brick> = Vec::with_capacity(falling.len());
+    let mut supports: Vec<Vec<BrickIndex>> = vec![Vec::new(); falling.len()];
+    let mut supported_by: Vec<Vec<BrickIndex>> = vec![Vec::new(); falling.len()];
+    for (index, brick) in falling.into_iter().enumerate() {
+      let below: Vec<(CoordinateUnit, BrickIndex)> = brick.footprint()
+        .filter_map(|cell| height_map[cell])
+        .collect();
+      let resting_height = below.iter().map(|&(height, _)| height).max().unwrap_or(GROUND_LEVEL);
+
+      let mut supporters: Vec<BrickIndex> = below.into_iter()
+        .filter(|&(height, _)| height == resting_height)
+        .map(|(_, supporter)| supporter)
+        .collect();
+      supporters.sort_unstable();
+      supporters.dedup();
+      for &supporter in &supporters {
+        supports[supporter].push(index);
+      }
+      supported_by[index] = supporters;
+
+      let settled = brick.dropped_to(resting_height + 1);
+      for cell in settled.footprint() {
+        height_map[cell] = Some((settled.high.z, index));
+      }
+      bricks.push(settled);
+    }
+
+    BrickStack { bricks, supports, supported_by }
+  }
+
+  /// A brick is safe to disintegrate when every brick it supports rests on some other brick too
+  pub fn safe_to_disintegrate(&self) -> usize {
+    self.supports.iter()
+      .filter(|supported| supported.iter().all(|&above| self.supported_by[above].len() > 1))
+      .count()
+  }
+
+  /// Bricks that fall when 'index' is disintegrated: the ones left with every supporter fallen
+  pub fn chain_reaction(&self, index: BrickIndex) -> usize {
+    let mut fallen_supporters: HashMap<BrickIndex, usize> = HashMap::new();
+    let mut to_process: VecDeque<BrickIndex> = VecDeque::from([index]);
+    let mut falling = 0;
+
+    while let Some(fallen) = to_process.pop_front() {
+      for &above in &self.supports[fallen] {
+        let count = fallen_supporters.entry(above).or_insert(0);
+        *count += 1;
+        if *count == self.supported_by[above].len() {
+          falling += 1;
+          to_process.push_back(above);
+        }
+      }
+    }
+
+    falling
+  }
+
+  pub fn total_chain_reactions(&self) -> usize {
+    (0..self.bricks.len()).map(|index| self.chain_reaction(index)).sum()
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = Vec<Brick>;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    text.lines().enumerate()
+      .map(|(line_index, line)| parse_brick(line_index, line))
+      .collect()
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.iter()
+      .map(|brick| format!("{},{},{}~{},{},{}",
+        brick.low.x, brick.low.y, brick.low.z, brick.high.x, brick.high.y, brick.high.z))
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    BrickStack::settle(input).safe_to_disintegrate().into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    BrickStack::settle(input).total_chain_reactions().into()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+  #[test]
+  fn bricks_settle_onto_supporters() {
+    let stack = BrickStack::settle(&Puzzle::parse(EXAMPLE).unwrap());
+    assert_eq!(stack.bricks[6].low.z, 5);
+    assert_eq!(stack.supported_by[1], vec![0]);
+    assert_eq!(stack.supported_by[3], vec![1, 2]);
+    assert_eq!(stack.chain_reaction(0), 6);
+  }
+
+  #[test]
+  fn malformed_bricks_are_reported() {
+    assert!(Puzzle::parse("1,0,1~1,2").is_err());
+    assert!(Puzzle::parse("1,0,0~1,2,0").is_err());
+    assert!(Puzzle::parse("1,0,1~1,1000,1").is_err());
+  }
+
+  #[test]
+  fn example_part_one() {
+    assert_eq!(Puzzle::part_one(&Puzzle::parse(EXAMPLE).unwrap()).to_string(), "5");
+  }
+
+  #[test]
+  fn example_part_two() {
+    assert_eq!(Puzzle::part_two(&Puzzle::parse(EXAMPLE).unwrap()).to_string(), "7");
+  }
+}
@@ -0,0 +1,23 @@
+// Imports
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_22::Puzzle;
+
+fn main() {
+
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
+    // Part 1
+    let safe_bricks = Puzzle::part_one(&input);
+    println!("\r🧱 Bricks safe to disintegrate: '{}' (Part 1)", safe_bricks);
+
+    // Part 2
+    let falling_bricks = Puzzle::part_two(&input);
+    println!("\r🧱 Sum of bricks falling in chain reactions: '{}' (Part 2)", falling_bricks);
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+use aoc_common::error::InputError;
+use aoc_common::grid::{Coordinate, CoordinateUnit, Direction, Grid};
+use aoc_common::solver::{Answer, Solver};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+type JunctionIndex = usize;
+type Distance = u32;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Tile {
+  Path,
+  Forest,
+  Slope(Direction)
+}
+
+/// Corridor between two junctions, with whether it can be walked without climbing a slope
+#[derive(Clone, Copy, Debug)]
+struct Trail {
+  to: JunctionIndex,
+  length: Distance,
+  downhill: bool,
+}
+
+/// The map compressed to its junctions, the start and the end, joined by the corridors between them
+pub struct TrailMap {
+  tiles: Grid<Tile>,
+  trails: Vec<Vec<Trail>>,
+  start: JunctionIndex,
+  end: JunctionIndex,
+}
+
+pub struct Puzzle;
+
+// ==================================================== CONSTANTS ====================================================
+const TILE_PATH: char = '.';
+const TILE_FOREST: char = '#';
+const TILE_SLOPE_UP: char = '^';
+const TILE_SLOPE_DOWN: char = 'v';
+const TILE_SLOPE_RIGHT: char = '>';
+const TILE_SLOPE_LEFT: char = '<';
+/// Junctions are tracked on a 'u64' bitmask while hiking, so maps with more are rejected when parsed
+const MAX_JUNCTIONS: usize = 64;
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+fn find_path_in_row(tiles: &Grid<Tile>, y: usize) -> Option<Coordinate> {
+  tiles.row(y).iter()
+    .position(|&tile| tile == Tile::Path)
+    .map(|x| Coordinate::new(x as CoordinateUnit, y as CoordinateUnit))
+}
+
+/// Longest hike from 'current' to 'end' not visiting any junction in 'visited' twice
+fn longest_hike(trail_map: &TrailMap, slippery: bool, current: JunctionIndex, visited: u64) -> Option<Distance> {
+  if current == trail_map.end {
+    return Some(0);
+  }
+
+  trail_map.trails[current].iter()
+    .filter(|trail| (!slippery || trail.downhill) && visited & (1 << trail.to) == 0)
+    .filter_map(|trail| longest_hike(trail_map, slippery, trail.to, visited | (1 << trail.to))
+      .map(|length| length + trail.length))
+    .max()
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Tile {
+  fn from(value: char) -> Option<Self> {
+    match value {
+      TILE_PATH => Some(Tile::Path),
+      TILE_FOREST => Some(Tile::Forest),
+      TILE_SLOPE_UP => Some(Tile::Slope(Direction::Up)),
+      TILE_SLOPE_DOWN => Some(Tile::Slope(Direction::Down)),
+      TILE_SLOPE_RIGHT => Some(Tile::Slope(Direction::Right)),
+      TILE_SLOPE_LEFT => Some(Tile::Slope(Direction::Left)),
+      _ => None,
+    }
+  }
+}
+
+impl From<Tile> for char {
+  fn from(value: Tile) -> Self {
+    match value {
+      Tile::Path => TILE_PATH,
+      Tile::Forest => TILE_FOREST,
+      Tile::Slope(Direction::Up) => TILE_SLOPE_UP,
+      Tile::Slope(Direction::Down) => TILE_SLOPE_DOWN,
+      Tile::Slope(Direction::Right) => TILE_SLOPE_RIGHT,
+      Tile::Slope(Direction::Left) => TILE_SLOPE_LEFT,
+    }
+  }
+}
+
+impl TrailMap {
+  pub fn new(text: &str) -> Result<TrailMap, InputError> {
+    let tiles: Grid<Tile> = Grid::parse_text(text, Tile::from)?;
+
+    let no_path = |row: usize| InputError::parse(row + 1, 1, text.lines().nth(row).unwrap_or(""), "row has no path");
+    let start = find_path_in_row(&tiles, 0).ok_or_else(|| no_path(0))?;
+    let end = find_path_in_row(&tiles, tiles.height() - 1).ok_or_else(|| no_path(tiles.height() - 1))?;
+
+    let trail_map = TrailMap::compress(tiles, start, end);
+    if trail_map.trails.len() > MAX_JUNCTIONS {
+      return Err(InputError::parse(1, 1, text.lines().next().unwrap_or(""),
+        format!("map has more than {} junctions", MAX_JUNCTIONS)));
+    }
+    Ok(trail_map)
+  }
+
+  /// Junctions are the tiles with more than two open neighbours, plus the start and the end. Every corridor
+  /// leaving a junction is followed until the next one, remembering whether a slope was climbed on the way
+  fn compress(tiles: Grid<Tile>, start: Coordinate, end: Coordinate) -> TrailMap {
+    let is_open = |position: &Coordinate| tiles.get(position).is_some_and(|&tile| tile != Tile::Forest);
+    let open_neighbours = |position: Coordinate| Direction::ALL.iter()
+      .map(move |&direction| (direction, position + direction.delta()))
+      .filter(|(_, neighbour)| is_open(neighbour))
+      .collect::<Vec<_>>();
+
+    let mut junctions: Vec<Coordinate> = vec![start, end];
+    junctions.extend(tiles.positions(|&tile| tile != Tile::Forest)
+      .filter(|&position| position != start && position != end && open_neighbours(position).len() > 2));
+    let indexes: HashMap<Coordinate, JunctionIndex> = junctions.iter().enumerate()
+      .map(|(index, &position)| (position, index))
+      .collect();
+
+    let trails = junctions.iter()
+      .map(|&junction| open_neighbours(junction).into_iter()
+        .filter_map(|(mut direction, mut position)| {
+          let mut previous = junction;
+          let mut length = 1;
+          let mut downhill = true;
+          loop {
+            if let Tile::Slope(slope) = tiles[position] {
+              downhill &= slope == direction;
+            }
+            if let Some(&to) = indexes.get(&position) {
+              return Some(Trail { to, length, downhill });
+            }
+
+            let (next_direction, next_position) = open_neighbours(position).into_iter()
+              .find(|&(_, neighbour)| neighbour != previous)?;
+            previous = position;
+            (direction, position) = (next_direction, next_position);
+            length += 1;
+          }
+        })
+        .collect())
+      .collect();
+
+    TrailMap { tiles, trails, start: 0, end: 1 }
+  }
+
+  /// Longest hike from the start to the end without stepping twice on the same tile, None when there is none
+  pub fn longest_hike(&self, slippery: bool) -> Option<Distance> {
+    longest_hike(self, slippery, self.start, 1 << self.start)
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = TrailMap;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    TrailMap::new(text)
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.tiles.to_string().trim_end_matches('\n').to_owned()
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    input.longest_hike(true).map_or_else(|| Answer::unsolved("There is no hike down the slopes"), Answer::from)
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    input.longest_hike(false).map_or_else(|| Answer::unsolved("There is no hike to the end"), Answer::from)
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+  #[test]
+  fn example_compresses_to_junctions() {
+    let trail_map = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(trail_map.trails.len(), 9);
+    assert_eq!(trail_map.trails[trail_map.start].len(), 1);
+  }
+
+  #[test]
+  fn unknown_tiles_are_reported() {
+    assert!(Puzzle::parse("#.#\n#x#\n#.#").is_err());
+    assert!(Puzzle::parse("###\n#.#\n#.#").is_err());
+  }
+
+  #[test]
+  fn example_part_one() {
+    assert_eq!(Puzzle::part_one(&Puzzle::parse(EXAMPLE).unwrap()).to_string(), "94");
+  }
+
+  #[test]
+  fn example_part_two() {
+    assert_eq!(Puzzle::part_two(&Puzzle::parse(EXAMPLE).unwrap()).to_string(), "154");
+  }
+
+  #[test]
+  fn uphill_slope_blocks_the_hike() {
+    let trail_map = Puzzle::parse("#.#\n#^#\n#.#").unwrap();
+    assert!(matches!(Puzzle::part_one(&trail_map), Answer::Unsolved(_)));
+    assert_eq!(Puzzle::part_two(&trail_map).to_string(), "2");
+  }
+}
@@ -0,0 +1,23 @@
+// Imports
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_23::Puzzle;
+
+fn main() {
+
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
+    // Part 1
+    let hike = Puzzle::part_one(&input);
+    println!("\r🥾 Longest hike down the slopes: '{}' (Part 1)", hike);
+
+    // Part 2
+    let dry_hike = Puzzle::part_two(&input);
+    println!("\r🥾 Longest hike climbing the slopes: '{}' (Part 2)", dry_hike);
+}
@@ -0,0 +1,226 @@
+use aoc_common::error::InputError;
+use aoc_common::grid::{Coordinate3D, CoordinateUnit};
+use aoc_common::rational::{gcd, Rational, RationalUnit};
+use aoc_common::solver::{Answer, Solver};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+type Vector = [RationalUnit; 3];
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Hailstone {
+  pub position: Coordinate3D,
+  pub velocity: Coordinate3D,
+}
+
+pub struct Puzzle;
+
+// ==================================================== CONSTANTS ====================================================
+const TEST_AREA_MIN: CoordinateUnit = 200000000000000;
+const TEST_AREA_MAX: CoordinateUnit = 400000000000000;
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+fn parse_hailstone(line_index: usize, line: &str) -> Result<Hailstone, InputError> {
+  let error = |token: &str, reason: String| InputError::at_token(line_index + 1, line, token.trim_start(), reason);
+  let (position, velocity) = line.split_once('@')
+    .ok_or_else(|| error(line, "hailstone is not formatted as 'x, y, z @ vx, vy, vz'".to_owned()))?;
+
+  Ok(Hailstone {
+    position: position.parse().map_err(|reason| error(position, reason))?,
+    velocity: velocity.parse().map_err(|reason| error(velocity, reason))?,
+  })
+}
+
+fn vector(coordinate: Coordinate3D) -> Vector {
+  [coordinate.x as RationalUnit, coordinate.y as RationalUnit, coordinate.z as RationalUnit]
+}
+
+/// Cross product, None when a component does not fit a 'RationalUnit'
+fn cross(a: Vector, b: Vector) -> Option<Vector> {
+  let product = |i: usize, j: usize| a[i].checked_mul(b[j])?.checked_sub(a[j].checked_mul(b[i])?);
+  Some([product(1, 2)?, product(2, 0)?, product(0, 1)?])
+}
+
+/// Smallest integer vector with the same direction
+fn reduce(vector: Vector) -> Vector {
+  let divisor = vector.iter().fold(0, |divisor, &value| gcd(divisor, value)).max(1);
+  vector.map(|value| value / divisor)
+}
+
+/// Whether the paths of two hailstones cross inside the test area, ignoring 'z', on both of their futures.
+/// Solving 'a.p + t * a.v = b.p + s * b.v' with Cramer's rule keeps the crossing point exact
+fn paths_cross_in_area(a: &Hailstone, b: &Hailstone, area_min: CoordinateUnit, area_max: CoordinateUnit) -> bool {
+  let ([apx, apy, _], [avx, avy, _]) = (vector(a.position), vector(a.velocity));
+  let ([bpx, bpy, _], [bvx, bvy, _]) = (vector(b.position), vector(b.velocity));
+  let determinant = avx * bvy - avy * bvx;
+  if determinant == 0 {
+    return false;
+  }
+
+  let (dx, dy) = (bpx - apx, bpy - apy);
+  let t = Rational::new(dx * bvy - dy * bvx, determinant);
+  let s = Rational::new(dx * avy - dy * avx, determinant);
+  if t < Rational::ZERO || s < Rational::ZERO {
+    return false;
+  }
+
+  let area = Rational::from(area_min)..=Rational::from(area_max);
+  area.contains(&(Rational::from(apx) + t * Rational::from(avx)))
+    && area.contains(&(Rational::from(apy) + t * Rational::from(avy)))
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Hailstone {
+  pub fn count_crossings(hailstones: &[Hailstone], area_min: CoordinateUnit, area_max: CoordinateUnit) -> usize {
+    hailstones.iter().enumerate()
+      .flat_map(|(index, a)| hailstones[index + 1..].iter().map(move |b| (a, b)))
+      .filter(|(a, b)| paths_cross_in_area(a, b, area_min, area_max))
+      .count()
+  }
+
+  /// Position and velocity of a rock thrown at time zero that hits every hailstone, None when there is none.
+  ///
+  /// Seen from the first hailstone, which then stays at the origin, the rock path goes through the origin. It
+  /// must also meet the path of every other hailstone, so it lies on the plane holding the origin and that path,
+  /// of normal 'q × w'. The linear system of two such planes is solved exactly by the cross product of their
+  /// normals, giving the rock direction. Each hailstone is then hit at the time its path crosses that line.
+  /// Products too large to be kept exact also give None.
+  pub fn rock_throw(hailstones: &[Hailstone]) -> Option<(Coordinate3D, Coordinate3D)> {
+    let (origin, others) = hailstones.split_first()?;
+    let relative: Vec<(Vector, Vector)> = others.iter()
+      .map(|hailstone| (vector(hailstone.position - origin.position), vector(hailstone.velocity - origin.velocity)))
+      .collect();
+
+    let normals: Vec<Vector> = relative.iter().map(|&(q, w)| cross(q, w).map(reduce)).collect::<Option<_>>()?;
+    let direction = normals.iter().enumerate()
+      .flat_map(|(index, &first)| normals[index + 1..].iter().map(move |&second| cross(first, second)))
+      .find(|&direction| direction != Some([0; 3]))
+      .flatten()
+      .map(reduce)?;
+
+    // Hitting 'q + t * w' on the rock line means '(q + t * w) × direction = 0', solved for 't'. A hailstone
+    // moving along the rock line is hit at a time only the rock velocity tells, so it is left to the final check
+    let mut hits: Vec<(Rational, [Rational; 3])> = Vec::new();
+    for &(q, w) in &relative {
+      let (offset, drift) = (cross(q, direction)?, cross(w, direction)?);
+      let Some(axis) = (0..3).find(|&axis| drift[axis] != 0) else {
+        if offset != [0; 3] {
+          return None;
+        }
+        continue;
+      };
+      let time = Rational::new(-offset[axis], drift[axis]);
+      if (0..3).any(|axis| Rational::from(offset[axis]) + time * Rational::from(drift[axis]) != Rational::ZERO) {
+        return None;
+      }
+
+      let hit = [0, 1, 2].map(|axis| Rational::from(q[axis]) + time * Rational::from(w[axis]));
+      if hits.iter().all(|&(other_time, _)| other_time != time) {
+        hits.push((time, hit));
+      }
+    }
+
+    let [(first_time, first_hit), (second_time, second_hit), ..] = hits[..] else { return None };
+    let velocity = [0, 1, 2].map(|axis| (second_hit[axis] - first_hit[axis]) / (second_time - first_time));
+    let position = [0, 1, 2].map(|axis| first_hit[axis] - first_time * velocity[axis]);
+
+    // Back from the first hailstone point of view, which was at its own position on time zero
+    let absolute = |relative: [Rational; 3], offset: Coordinate3D| -> Option<Coordinate3D> {
+      let [x, y, z] = [0, 1, 2].map(|axis| (relative[axis] + Rational::from(vector(offset)[axis])).to_integer());
+      Some(Coordinate3D::new(x? as CoordinateUnit, y? as CoordinateUnit, z? as CoordinateUnit))
+    };
+    let (position, velocity) = (absolute(position, origin.position)?, absolute(velocity, origin.velocity)?);
+    hailstones.iter()
+      .all(|hailstone| hailstone.is_hit_by(position, velocity))
+      .then_some((position, velocity))
+  }
+
+  /// Whether a rock thrown at time zero meets the hailstone at some time from then on
+  fn is_hit_by(&self, position: Coordinate3D, velocity: Coordinate3D) -> bool {
+    let gap: Vector = [0, 1, 2].map(|axis| vector(self.position)[axis] - vector(position)[axis]);
+    let closing: Vector = [0, 1, 2].map(|axis| vector(velocity)[axis] - vector(self.velocity)[axis]);
+    let Some(axis) = (0..3).find(|&axis| closing[axis] != 0) else { return gap == [0; 3] };
+
+    let time = Rational::new(gap[axis], closing[axis]);
+    time >= Rational::ZERO && (0..3).all(|axis| Rational::from(gap[axis]) == time * Rational::from(closing[axis]))
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = Vec<Hailstone>;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    text.lines().enumerate()
+      .map(|(line_index, line)| parse_hailstone(line_index, line))
+      .collect()
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.iter()
+      .map(|Hailstone { position: p, velocity: v }| format!("{}, {}, {} @ {}, {}, {}", p.x, p.y, p.z, v.x, v.y, v.z))
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    Hailstone::count_crossings(input, TEST_AREA_MIN, TEST_AREA_MAX).into()
+  }
+
+  fn part_two(input: &Self::Input) -> Answer {
+    Hailstone::rock_throw(input).map_or_else(
+      || Answer::unsolved("No single throw hits every hailstone"),
+      |(position, _)| (position.x + position.y + position.z).into())
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+
+  #[test]
+  fn malformed_hailstones_are_reported() {
+    assert!(Puzzle::parse("19, 13, 30 -2, 1, -2").is_err());
+    assert!(Puzzle::parse("19, 13 @ -2, 1, -2").is_err());
+  }
+
+  #[test]
+  fn example_part_one() {
+    let hailstones = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Hailstone::count_crossings(&hailstones, 7, 27), 2);
+  }
+
+  #[test]
+  fn example_part_two() {
+    let hailstones = Puzzle::parse(EXAMPLE).unwrap();
+    assert_eq!(Hailstone::rock_throw(&hailstones), Some((Coordinate3D::new(24, 13, 10), Coordinate3D::new(-3, 1, 2))));
+    assert_eq!(Puzzle::part_two(&hailstones).to_string(), "47");
+  }
+
+  #[test]
+  fn missed_hailstones_leave_no_throw() {
+    let standing = Puzzle::parse(&format!("{}\n0, 0, 0 @ 0, 0, 0", EXAMPLE)).unwrap();
+    assert_eq!(Hailstone::rock_throw(&standing), None);
+    assert!(matches!(Puzzle::part_two(&standing), Answer::Unsolved(_)));
+
+    let alongside = Puzzle::parse(&format!("{}\n0, 0, 0 @ -3, 1, 2", EXAMPLE)).unwrap();
+    assert_eq!(Hailstone::rock_throw(&alongside), None);
+  }
+
+  #[test]
+  fn input_sized_hailstones_without_throw() {
+    let hailstones = Puzzle::parse("\
+200000000000000, 300000000000000, 250000000000000 @ 10, -20, 30
+310000000000123, 180000000000456, 270000000000789 @ -15, 25, -5
+260000000000321, 220000000000654, 330000000000987 @ 40, 7, -33
+190000000000111, 370000000000222, 210000000000333 @ -8, -41, 12").unwrap();
+    assert_eq!(Hailstone::rock_throw(&hailstones), None);
+    assert!(matches!(Puzzle::part_two(&hailstones), Answer::Unsolved(_)));
+  }
+}
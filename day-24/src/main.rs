@@ -0,0 +1,23 @@
+// Imports
+use std::env;
+
+use aoc_common::read::Source;
+use aoc_common::solver;
+use aoc_common::solver::Solver;
+use day_24::Puzzle;
+
+fn main() {
+
+    // Input path can be given as first argument, '-' reading from stdin
+    let path = env::args().nth(1).unwrap_or_else(|| "input.txt".to_owned());
+    let input = solver::load::<Puzzle>(&Source::from_arg(&path))
+        .unwrap_or_else(|error| error.report());
+
+    // Part 1
+    let crossings = Puzzle::part_one(&input);
+    println!("\r🌨️ Hailstone paths crossing in the test area: '{}' (Part 1)", crossings);
+
+    // Part 2
+    let rock_position = Puzzle::part_two(&input);
+    println!("\r🌨️ Sum of the rock starting coordinates: '{}' (Part 2)", rock_position);
+}
@@ -0,0 +1,221 @@
+use std::collections::{HashMap, VecDeque};
+use aoc_common::error::InputError;
+use aoc_common::graph::{Graph, NodeIndex};
+use aoc_common::solver::{Answer, Solver};
+
+// =========================================== TYPE AND STRUCT DEFINITIONS ===========================================
+pub struct Wiring {
+  components: Graph<String, ()>,
+  /// Every line of the diagram as listed, a component and the ones it is wired to
+  diagram: Vec<(NodeIndex, Vec<NodeIndex>)>,
+}
+
+/// Wires whose removal splits the components in two groups, and the components of the group holding the first one
+#[derive(Debug)]
+pub struct WireCut {
+  pub wires: Vec<(NodeIndex, NodeIndex)>,
+  pub group: Vec<NodeIndex>,
+}
+
+/// Units of flow sent through each wire in each direction, '-1' meaning the opposite direction carries one
+type Flows = HashMap<(NodeIndex, NodeIndex), i32>;
+
+pub struct Puzzle;
+
+// ==================================================== CONSTANTS ====================================================
+const WIRES_TO_CUT: usize = 3;
+const SNOW_PRODUCTION: &str = "Push the big red button";
+
+// =============================================== AUXILIARY FUNCTIONS ===============================================
+fn parse_connections(line_index: usize, line: &str) -> Result<(&str, Vec<&str>), InputError> {
+  let error = |token: &str, reason: &str| InputError::at_token(line_index + 1, line, token, reason);
+  let (component, connected) = line.split_once(':')
+    .ok_or_else(|| error(line, "line is not formatted as 'component: connected components'"))?;
+  let connected: Vec<&str> = connected.split_whitespace().collect();
+  if component.trim().is_empty() || connected.is_empty() {
+    return Err(error(line, "component has no name or no connections"));
+  }
+  let is_name = |name: &&str| name.chars().all(|characther| characther.is_ascii_lowercase());
+  if let Some(name) = std::iter::once(component.trim()).chain(connected.iter().copied()).find(|name| !is_name(name)) {
+    return Err(error(name, "component name is not made of lowercase letters"));
+  }
+
+  Ok((component.trim(), connected))
+}
+
+// ================================================= IMPLEMENTATIONS =================================================
+impl Wiring {
+  pub fn new(text: &str) -> Result<Wiring, InputError> {
+    let mut components: Graph<String, ()> = Graph::new();
+    let mut diagram: Vec<(NodeIndex, Vec<NodeIndex>)> = Vec::new();
+    for (line_index, line) in text.lines().enumerate() {
+      let (component, connected) = parse_connections(line_index, line)?;
+      let component = components.add_node(component.to_owned());
+      let mut wired = Vec::new();
+      for other in connected {
+        let other = components.add_node(other.to_owned());
+        components.add_undirected_edge(component, (), other);
+        wired.push(other);
+      }
+      diagram.push((component, wired));
+    }
+
+    Ok(Wiring { components, diagram })
+  }
+
+  fn render(&self) -> String {
+    self.diagram.iter()
+      .map(|(component, wired)| {
+        let wired: Vec<&str> = wired.iter().map(|&other| self.component_name(other)).collect();
+        format!("{}: {}", self.component_name(*component), wired.join(" "))
+      })
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  /// Breadth-first search over the wires still able to carry flow, returning the predecessor of every
+  /// component reached from 'source'
+  fn residual_search(&self, source: NodeIndex, flows: &Flows) -> Vec<Option<NodeIndex>> {
+    let mut previous: Vec<Option<NodeIndex>> = vec![None; self.components.len()];
+    previous[source] = Some(source);
+    let mut to_process: VecDeque<NodeIndex> = VecDeque::from([source]);
+
+    while let Some(from) = to_process.pop_front() {
+      for to in self.components.neighbours(from) {
+        if previous[to].is_none() && flows.get(&(from, to)).copied().unwrap_or(0) < 1 {
+          previous[to] = Some(from);
+          to_process.push_back(to);
+        }
+      }
+    }
+
+    previous
+  }
+
+  /// Counts edge-disjoint paths between both components by augmenting one unit of flow at a time, stopping
+  /// once there are more than 'limit' of them
+  fn disjoint_paths(&self, source: NodeIndex, sink: NodeIndex, limit: usize, flows: &mut Flows) -> usize {
+    for paths in 0..=limit {
+      let previous = self.residual_search(source, flows);
+      if previous[sink].is_none() {
+        return paths;
+      }
+
+      let mut to = sink;
+      while to != source {
+        let from = previous[to].unwrap();
+        *flows.entry((from, to)).or_insert(0) += 1;
+        *flows.entry((to, from)).or_insert(0) -= 1;
+        to = from;
+      }
+    }
+
+    limit + 1
+  }
+
+  /// Minimum cut of exactly 'wires' wires, through max-flow: a component on the other side of the cut from the
+  /// first one is joined to it by exactly 'wires' edge-disjoint paths. Once their flow saturates the network,
+  /// the components still reachable from the first one form its group and the saturated wires are the cut
+  pub fn minimum_cut(&self, wires: usize) -> Option<WireCut> {
+    let source = 0;
+    (1..self.components.len()).find_map(|sink| {
+      let mut flows: Flows = HashMap::new();
+      if self.disjoint_paths(source, sink, wires, &mut flows) != wires {
+        return None;
+      }
+
+      let reached = self.residual_search(source, &flows);
+      let group: Vec<NodeIndex> = (0..self.components.len()).filter(|&index| reached[index].is_some()).collect();
+      let wires = group.iter()
+        .flat_map(|&from| self.components.neighbours(from).map(move |to| (from, to)))
+        .filter(|&(_, to)| reached[to].is_none())
+        .collect();
+      Some(WireCut { wires, group })
+    })
+  }
+
+  pub fn component_name(&self, index: NodeIndex) -> &str {
+    self.components.node(index)
+  }
+
+  pub fn groups_product(&self) -> Option<usize> {
+    let cut = self.minimum_cut(WIRES_TO_CUT)?;
+    Some(cut.group.len() * (self.components.len() - cut.group.len()))
+  }
+}
+
+impl Solver for Puzzle {
+  type Input = Wiring;
+
+  fn parse(text: &str) -> Result<Self::Input, InputError> {
+    Wiring::new(text)
+  }
+
+  fn render(input: &Self::Input) -> String {
+    input.render()
+  }
+
+  fn part_one(input: &Self::Input) -> Answer {
+    input.groups_product().map_or_else(
+      || Answer::unsolved(format!("No '{}' wires split the components in two groups", WIRES_TO_CUT)),
+      Answer::from)
+  }
+
+  fn part_two(_input: &Self::Input) -> Answer {
+    SNOW_PRODUCTION.into()
+  }
+}
+
+// ====================================================== TESTS ======================================================
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+  #[test]
+  fn example_cut_wires() {
+    let wiring = Puzzle::parse(EXAMPLE).unwrap();
+    let cut = wiring.minimum_cut(WIRES_TO_CUT).unwrap();
+    let mut wires: Vec<(&str, &str)> = cut.wires.iter()
+      .map(|&(from, to)| {
+        let (from, to) = (wiring.component_name(from), wiring.component_name(to));
+        (from.min(to), from.max(to))
+      })
+      .collect();
+    wires.sort();
+    assert_eq!(wires, vec![("bvb", "cmg"), ("hfx", "pzl"), ("jqt", "nvd")]);
+  }
+
+  #[test]
+  fn malformed_connections_are_reported() {
+    assert!(Puzzle::parse("jqt rhn xhk").is_err());
+    assert!(Puzzle::parse("jqt:").is_err());
+    assert!(Puzzle::parse("jqt: rhn, xhk").is_err());
+  }
+
+  #[test]
+  fn example_part_one() {
+    assert_eq!(Puzzle::part_one(&Puzzle::parse(EXAMPLE).unwrap()).to_string(), "54");
+  }
+
+  #[test]
+  fn chain_without_three_wire_cut() {
+    let wiring = Puzzle::parse("abc: def\ndef: ghi").unwrap();
+    assert_eq!(wiring.groups_product(), None);
+    assert!(matches!(Puzzle::part_one(&wiring), Answer::Unsolved(_)));
+  }
+}
@@ -0,0 +1,22 @@
+//! Seeds the input generators of 'aoc::inputs' from the fuzzer's bytes: valid inputs must render back to the same
+//! text, and corrupted ones must be rejected with an error.
+
+#![no_main]
+
+use aoc::days;
+use aoc::inputs;
+use aoc_common::random::Random;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: [u8; 9]| {
+  let day = 1 + data[0] % 25;
+  let seed = u64::from_le_bytes(data[1..].try_into().unwrap());
+
+  let valid = inputs::valid(day, &mut Random::new(seed)).expect("🚨 Day has no input generator");
+  let rendered = days::round_trip(day, &valid).expect("🚨 Day has no solution");
+  assert_eq!(rendered.ok().as_ref(), Some(&valid), "🚨 Day {:02} did not render its input back", day);
+
+  let corrupted = inputs::corrupted(day, &mut Random::new(seed)).expect("🚨 Day has no input generator");
+  let result = days::round_trip(day, &corrupted).expect("🚨 Day has no solution");
+  assert!(result.is_err(), "🚨 Day {:02} accepted a corrupted input:\n{}", day, corrupted);
+});
@@ -0,0 +1,18 @@
+//! Feeds arbitrary text to a day's parser: the first byte picks the day, the rest is the input. Parsing must
+//! never panic, and whatever parses must render to a text that parses back to itself.
+
+#![no_main]
+
+use aoc::days;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  let Some((&selector, rest)) = data.split_first() else { return };
+  let Ok(text) = std::str::from_utf8(rest) else { return };
+  let day = 1 + selector % 25;
+
+  if let Some(Ok(rendered)) = days::round_trip(day, text) {
+    let again = days::round_trip(day, &rendered).expect("🚨 Day has no solution");
+    assert_eq!(again.ok().as_ref(), Some(&rendered), "🚨 Day {:02} did not render its input back", day);
+  }
+});